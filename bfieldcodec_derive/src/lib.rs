@@ -35,6 +35,19 @@ use syn::Variant;
 /// `#[bfield_codec(ignore)]`.
 /// Ignored fields must implement [`Default`].
 ///
+/// Conditionally compiled fields, _i.e._, fields annotated with `#[cfg(...)]`, must be ignored.
+/// This guarantees that the encoding does not depend on the configuration.
+///
+/// The encoding length of a field can be capped by annotating it with
+/// `#[bfield_codec(max_length = N)]`, where `N` is the maximal number of `BFieldElement`s the
+/// field's encoding may span. Decoding fails if the field's length indicator exceeds the cap,
+/// before the field itself is decoded. Since nested length indicators are bounded by the
+/// sequence they are embedded in, this also bounds the work spent on decoding nested fields, like
+/// `Vec<Vec<T>>`, from untrusted input.
+///
+/// Fields of type `PhantomData<T>` encode to nothing. Generic type parameters that only occur in
+/// such fields are not required to implement `BFieldCodec`.
+///
 /// For enums, the discriminant used for serialization can be accessed through method
 /// `bfield_codec_discriminant`.
 ///
@@ -53,6 +66,16 @@ use syn::Variant;
 /// assert_eq!(foo.bar, decoded.bar);
 /// ```
 ///
+/// Capping the length of a field:
+///
+/// ```ignore
+/// #[derive(BFieldCodec)]
+/// struct Message {
+///     #[bfield_codec(max_length = 1000)]
+///     payload: Vec<Vec<u64>>,
+/// }
+/// ```
+///
 /// Accessing the discriminant of an enum's variant:
 ///
 /// ```ignore
@@ -74,12 +97,16 @@ use syn::Variant;
 ///     }
 ///     ```
 ///
-/// - Enums with no variants are currently not supported. Consider using a unit struct instead.
-///     Example:
+/// - Enums with no variants are uninhabited and rejected at compile time. Consider using a unit
+///     struct instead. Example:
 ///     ```ignore
-///     #[derive(BFieldCodec)]  // Currently not supported.
+///     #[derive(BFieldCodec)]  // Compile error.
 ///     enum Foo {}             // Consider `struct Foo;` instead.
 ///     ```
+///
+/// - The discriminant is encoded as a single `BFieldElement`. Hence, the number of variants must
+///     be smaller than the field modulus `P` = 2^64 - 2^32 + 1. Enums with more variants are
+///     rejected at compile time.
 #[proc_macro_derive(BFieldCodec, attributes(bfield_codec))]
 pub fn bfieldcodec_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -118,6 +145,13 @@ struct BFieldCodecErrorEnumBuilder {
     errors: HashMap<&'static str, BFieldCodecErrorEnumVariant>,
 }
 
+/// The options set through a field's `#[bfield_codec(...)]` attribute.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct FieldAttributes {
+    is_ignored: bool,
+    max_length: Option<usize>,
+}
+
 struct BFieldCodecErrorEnumVariant {
     variant_name: Ident,
     variant_type: TokenStream,
@@ -133,8 +167,10 @@ impl BFieldCodecDeriveBuilder {
             .iter()
             .cloned()
             .partition::<Vec<_>, _>(Self::field_is_ignored);
+        Self::ensure_no_field_is_conditionally_compiled(&included_fields);
 
         let unnamed_fields = Self::extract_unnamed_fields(&ast);
+        Self::ensure_no_field_is_conditionally_compiled(&unnamed_fields);
         let variants = Self::extract_variants(&ast);
 
         let name = ast.ident;
@@ -205,34 +241,109 @@ impl BFieldCodecDeriveBuilder {
     }
 
     fn field_is_ignored(field: &Field) -> bool {
-        let field_name = field.ident.as_ref().unwrap();
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        Self::parse_field_attributes(field, &field_name).is_ignored
+    }
+
+    fn parse_field_attributes(field: &Field, field_name: &str) -> FieldAttributes {
         let mut relevant_attributes = field
             .attrs
             .iter()
             .filter(|attr| attr.path().is_ident("bfield_codec"));
         let attribute = match relevant_attributes.clone().count() {
-            0 => return false,
+            0 => return FieldAttributes::default(),
             1 => relevant_attributes.next().unwrap(),
             _ => panic!("field `{field_name}` must have at most 1 `bfield_codec` attribute"),
         };
-        let parse_ignore = attribute.parse_nested_meta(|meta| match meta.path.get_ident() {
-            Some(ident) if ident == "ignore" => Ok(()),
+
+        let mut field_attributes = FieldAttributes::default();
+        let parse_attributes = attribute.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) if ident == "ignore" => {
+                field_attributes.is_ignored = true;
+                Ok(())
+            }
+            Some(ident) if ident == "max_length" => {
+                let max_length = meta.value()?.parse::<syn::LitInt>()?.base10_parse()?;
+                field_attributes.max_length = Some(max_length);
+                Ok(())
+            }
             Some(ident) => panic!("unknown identifier `{ident}` for field `{field_name}`"),
             _ => unreachable!(),
         });
-        parse_ignore.is_ok()
+        if let Err(err) = parse_attributes {
+            panic!("invalid `bfield_codec` attribute for field `{field_name}`: {err}");
+        }
+        if field_attributes.is_ignored && field_attributes.max_length.is_some() {
+            panic!("field `{field_name}` is ignored and cannot have a maximum length");
+        }
+        field_attributes
+    }
+
+    /// A field behind `#[cfg(...)]` is only present in some configurations. If it were encoded,
+    /// the layout of the encoding would depend on the configuration, breaking compatibility
+    /// between differently configured peers. Only ignored fields may be conditionally compiled.
+    fn ensure_no_field_is_conditionally_compiled(included_fields: &[Field]) {
+        for (index, field) in included_fields.iter().enumerate() {
+            if !field.attrs.iter().any(|attr| attr.path().is_ident("cfg")) {
+                continue;
+            }
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or(index.to_string(), |ident| ident.to_string());
+            panic!(
+                "field `{field_name}` is conditionally compiled and must be annotated with \
+                `#[bfield_codec(ignore)]` to keep the encoding independent of the configuration"
+            );
+        }
     }
 
     fn build(mut self) -> TokenStream {
-        self.error_builder.build(self.derive_type);
+        let has_length_capped_fields = self.has_length_capped_fields();
+        self.error_builder
+            .build(self.derive_type, has_length_capped_fields);
         self.add_trait_bounds_to_generics();
         self.build_methods();
         self.into_tokens()
     }
 
+    /// Whether any field is annotated with `#[bfield_codec(max_length = N)]`. Only then does the
+    /// error enum get variants for exceeded maximum lengths, keeping it unchanged otherwise.
+    fn has_length_capped_fields(&self) -> bool {
+        let named_fields = self
+            .named_included_fields
+            .iter()
+            .map(|field| (field, field.ident.as_ref().unwrap().to_string()));
+        let unnamed_fields = self
+            .unnamed_fields
+            .iter()
+            .enumerate()
+            .map(|(field_index, field)| (field, field_index.to_string()));
+        let variant_fields = self.variants.iter().flatten().flat_map(|variant| {
+            let variant_name = &variant.ident;
+            variant
+                .fields
+                .iter()
+                .enumerate()
+                .map(move |(field_index, field)| {
+                    (field, format!("{field_index} of variant `{variant_name}`"))
+                })
+        });
+
+        named_fields
+            .chain(unnamed_fields)
+            .chain(variant_fields)
+            .any(|(field, field_name)| {
+                Self::parse_field_attributes(field, &field_name)
+                    .max_length
+                    .is_some()
+            })
+    }
+
     fn add_trait_bounds_to_generics(&mut self) {
         let ignored_generics = self.extract_ignored_generics_list();
-        let ignored_generics = self.recursively_collect_all_ignored_generics(ignored_generics);
+        let mut ignored_generics = self.recursively_collect_all_ignored_generics(ignored_generics);
+        ignored_generics.extend(self.generics_only_in_phantom_data());
 
         for param in &mut self.generics.params {
             let syn::GenericParam::Type(type_param) = param else {
@@ -276,19 +387,62 @@ impl BFieldCodecDeriveBuilder {
         &self,
         mut ignored_generics: Vec<Ident>,
     ) -> Vec<Ident> {
-        let mut ignored_types = self
+        let ignored_types = self
             .named_ignored_fields
             .iter()
             .map(|ignored_field| ignored_field.ty.clone())
             .collect::<Vec<_>>();
-        while !ignored_types.is_empty() {
-            let ignored_type = ignored_types[0].clone();
-            ignored_types = ignored_types[1..].to_vec();
-            let Type::Path(type_path) = ignored_type else {
+        ignored_generics.extend(Self::recursively_collect_type_identifiers(ignored_types));
+        ignored_generics
+    }
+
+    /// The type identifiers that only occur in included fields of type `PhantomData<_>`. Since
+    /// `PhantomData<T>` implements `BFieldCodec` for any `T`, such generics need no trait bound.
+    fn generics_only_in_phantom_data(&self) -> Vec<Ident> {
+        let included_fields = self
+            .named_included_fields
+            .iter()
+            .chain(&self.unnamed_fields)
+            .chain(self.variants.iter().flatten().flat_map(|v| &v.fields));
+        let (phantom_fields, other_fields) =
+            included_fields.partition::<Vec<_>, _>(|field| Self::is_phantom_data(&field.ty));
+
+        let types_of = |fields: Vec<&Field>| -> Vec<Type> {
+            fields.into_iter().map(|f| f.ty.clone()).collect()
+        };
+        let phantom_identifiers =
+            Self::recursively_collect_type_identifiers(types_of(phantom_fields));
+        let other_identifiers = Self::recursively_collect_type_identifiers(types_of(other_fields));
+
+        phantom_identifiers
+            .into_iter()
+            .filter(|ident| !other_identifiers.contains(ident))
+            .collect()
+    }
+
+    fn is_phantom_data(field_type: &Type) -> bool {
+        let Type::Path(type_path) = field_type else {
+            return false;
+        };
+        type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData")
+    }
+
+    /// All type identifiers of the given types, including, recursively, the type identifiers of
+    /// generic type arguments.
+    fn recursively_collect_type_identifiers(mut types: Vec<Type>) -> Vec<Ident> {
+        let mut identifiers = vec![];
+        while !types.is_empty() {
+            let current_type = types[0].clone();
+            types = types[1..].to_vec();
+            let Type::Path(type_path) = current_type else {
                 continue;
             };
             for segment in type_path.path.segments.into_iter() {
-                ignored_generics.push(segment.ident);
+                identifiers.push(segment.ident);
                 let syn::PathArguments::AngleBracketed(generic_arguments) = segment.arguments
                 else {
                     continue;
@@ -297,11 +451,11 @@ impl BFieldCodecDeriveBuilder {
                     let syn::GenericArgument::Type(t) = generic_argument else {
                         continue;
                     };
-                    ignored_types.push(t.clone());
+                    types.push(t.clone());
                 }
             }
         }
-        ignored_generics
+        identifiers
     }
 
     fn build_methods(&mut self) {
@@ -337,11 +491,42 @@ impl BFieldCodecDeriveBuilder {
     }
 
     fn build_methods_for_enum(&mut self) {
+        self.ensure_enum_has_variants();
+        self.ensure_discriminants_fit_into_field();
         self.build_encode_statements_for_enum();
         self.build_decode_function_body_for_enum();
         self.build_static_length_body_for_enum();
     }
 
+    /// An enum without variants is uninhabited: it can never be encoded, and decoding always
+    /// fails. Deriving `BFieldCodec` for it is almost certainly a mistake.
+    fn ensure_enum_has_variants(&self) {
+        if self.variants.as_ref().is_some_and(|v| v.is_empty()) {
+            let name = &self.name;
+            panic!(
+                "cannot derive `BFieldCodec` for enum `{name}` without variants: \
+                the type is uninhabited; consider using a unit struct instead"
+            );
+        }
+    }
+
+    /// Discriminants are encoded as a single `BFieldElement`. Distinct discriminants must remain
+    /// distinct after reduction modulo `P`.
+    fn ensure_discriminants_fit_into_field(&self) {
+        let num_variants = self.variants.as_ref().map_or(0, |v| v.len());
+        Self::ensure_variant_count_is_below_field_modulus(&self.name, num_variants);
+    }
+
+    fn ensure_variant_count_is_below_field_modulus(name: &Ident, num_variants: usize) {
+        const FIELD_MODULUS: u128 = 0xffff_ffff_0000_0001;
+        if num_variants as u128 >= FIELD_MODULUS {
+            panic!(
+                "cannot derive `BFieldCodec` for enum `{name}` with {num_variants} variants: \
+                the number of variants must be smaller than the field modulus {FIELD_MODULUS}"
+            );
+        }
+    }
+
     fn build_encode_statements_for_struct_with_named_fields(&mut self) {
         let included_field_names = self
             .named_included_fields
@@ -497,7 +682,9 @@ impl BFieldCodecDeriveBuilder {
             .iter()
             .map(|field| {
                 let field_name = field.ident.as_ref().unwrap();
-                self.generate_decode_statement_for_field(field_name, &field.ty)
+                let max_length =
+                    Self::parse_field_attributes(field, &field_name.to_string()).max_length;
+                self.generate_decode_statement_for_field(field_name, &field.ty, max_length)
             })
             .collect::<Vec<_>>();
 
@@ -531,9 +718,12 @@ impl BFieldCodecDeriveBuilder {
         let decode_statements = field_names
             .iter()
             .zip(self.unnamed_fields.iter())
+            .enumerate()
             .rev()
-            .map(|(field_name, field)| {
-                self.generate_decode_statement_for_field(field_name, &field.ty)
+            .map(|(field_index, (field_name, field))| {
+                let max_length =
+                    Self::parse_field_attributes(field, &field_index.to_string()).max_length;
+                self.generate_decode_statement_for_field(field_name, &field.ty, max_length)
             })
             .collect::<Vec<_>>();
 
@@ -550,10 +740,24 @@ impl BFieldCodecDeriveBuilder {
         &self,
         field_name: &Ident,
         field_type: &Type,
+        max_length: Option<usize>,
     ) -> TokenStream {
         let sequence_empty_for_field_error = self.error_builder.sequence_empty_for_field();
         let sequence_too_short_for_field_error = self.error_builder.sequence_too_short_for_field();
         let field_name_as_string_literal = field_name.to_string();
+        let decode_field = Self::generate_decode_expression(field_type);
+        let check_max_length = max_length.map(|max_length| {
+            let length_exceeds_maximum_for_field_error =
+                self.error_builder.length_exceeds_maximum_for_field();
+            quote! {
+                if len > #max_length {
+                    return ::core::result::Result::Err(#length_exceeds_maximum_for_field_error(
+                        #field_name_as_string_literal.to_string(),
+                        len,
+                    ));
+                }
+            }
+        });
         quote! {
             let (#field_name, sequence) = {
                 let maybe_fields_static_length =
@@ -569,27 +773,90 @@ impl BFieldCodecDeriveBuilder {
                     ::core::option::Option::Some(len) => (len, sequence),
                     ::core::option::Option::None => (sequence[0].value() as usize, &sequence[1..]),
                 };
+                #check_max_length
                 if sequence.len() < len {
                     return ::core::result::Result::Err(#sequence_too_short_for_field_error(
                         #field_name_as_string_literal.to_string(),
                     ));
                 }
-                let decoded =
-                    *<#field_type as crate::twenty_first::shared_math::bfield_codec::BFieldCodec>
-                        ::decode(&sequence[..len]).map_err(|err|
-                            -> ::std::boxed::Box<
-                                    dyn ::std::error::Error
-                                    + ::core::marker::Send
-                                    + ::core::marker::Sync
-                            > {
-                                err.into()
-                            }
-                        )?;
+                let decoded = #decode_field.map_err(|err|
+                    -> ::std::boxed::Box<
+                            dyn ::std::error::Error
+                            + ::core::marker::Send
+                            + ::core::marker::Sync
+                    > {
+                        err.into()
+                    }
+                )?;
                 (decoded, &sequence[len..])
             };
         }
     }
 
+    /// The expression decoding a field of the given type from `sequence[..len]`, evaluating to a
+    /// `Result`.
+    ///
+    /// Fields of type `Vec<T>` are decoded with
+    /// `twenty_first::shared_math::bfield_codec::decode_vec_field`, which derives the number of
+    /// elements from `len` if `T` has a static length. All other fields, including those whose
+    /// type is an alias of `Vec<T>`, are decoded through their `BFieldCodec` implementation.
+    fn generate_decode_expression(field_type: &Type) -> TokenStream {
+        if let Some(element_type) = Self::vec_element_type(field_type) {
+            return quote! {
+                crate::twenty_first::shared_math::bfield_codec::decode_vec_field::<#element_type>(
+                    &sequence[..len]
+                )
+            };
+        }
+
+        quote! {
+            <#field_type as crate::twenty_first::shared_math::bfield_codec::BFieldCodec>
+                ::decode(&sequence[..len])
+                .map(|boxed| *boxed)
+        }
+    }
+
+    /// The element type `T` if the given type is spelled `Vec<T>`, `std::vec::Vec<T>`, or
+    /// `alloc::vec::Vec<T>`, optionally with a leading `::`.
+    fn vec_element_type(field_type: &Type) -> Option<Type> {
+        let Type::Path(type_path) = field_type else {
+            return None;
+        };
+        if type_path.qself.is_some() {
+            return None;
+        }
+
+        let path = &type_path.path;
+        let identifiers = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        let is_vec = match identifiers.as_slice() {
+            [vec] => path.leading_colon.is_none() && vec == "Vec",
+            [krate, module, vec] => {
+                (krate == "std" || krate == "alloc") && module == "vec" && vec == "Vec"
+            }
+            _ => false,
+        };
+        if !is_vec {
+            return None;
+        }
+
+        let syn::PathArguments::AngleBracketed(generic_arguments) =
+            &path.segments.last()?.arguments
+        else {
+            return None;
+        };
+        let mut generic_arguments = generic_arguments.args.iter();
+        let (Some(syn::GenericArgument::Type(element_type)), None) =
+            (generic_arguments.next(), generic_arguments.next())
+        else {
+            return None;
+        };
+        Some(element_type.clone())
+    }
+
     fn build_decode_function_body_for_enum(&mut self) {
         let sequence_empty_error = self.error_builder.sequence_empty();
         let invalid_variant_error = self.error_builder.invalid_discriminant();
@@ -642,6 +909,20 @@ impl BFieldCodecDeriveBuilder {
                 let field_name = self.enum_variant_field_name(discriminant, field_index);
                 let field_value =
                     quote::format_ident!("variant_{}_field_{}_value", discriminant, field_index);
+                let decode_field = Self::generate_decode_expression(&field_type);
+                let field_description = format!("{field_index} of variant `{variant_name}`");
+                let max_length = Self::parse_field_attributes(field, &field_description).max_length;
+                let check_max_length = max_length.map(|max_length| {
+                    let length_exceeds_maximum_error =
+                        self.error_builder.length_exceeds_maximum_for_variant();
+                    quote! {
+                        if len > #max_length {
+                            return ::core::result::Result::Err(
+                                #length_exceeds_maximum_error(#discriminant, #field_index, len)
+                            );
+                        }
+                    }
+                });
                 quote! {
                     let (#field_value, sequence) = {
                         let maybe_fields_static_length =
@@ -659,24 +940,21 @@ impl BFieldCodecDeriveBuilder {
                                 (sequence[0].value() as usize, &sequence[1..])
                             },
                         };
+                        #check_max_length
                         if sequence.len() < len {
                             return ::core::result::Result::Err(
                                 #sequence_too_short_error(#discriminant, #field_index)
                             );
                         }
-                        let decoded =
-                            *<#field_type as crate::twenty_first::shared_math::bfield_codec::BFieldCodec>
-                                ::decode(
-                                    &sequence[..len]
-                                ).map_err(|err|
-                                    -> ::std::boxed::Box<
-                                            dyn ::std::error::Error
-                                            + ::core::marker::Send
-                                            + ::core::marker::Sync
-                                    > {
-                                        err.into()
-                                    }
-                                )?;
+                        let decoded = #decode_field.map_err(|err|
+                            -> ::std::boxed::Box<
+                                    dyn ::std::error::Error
+                                    + ::core::marker::Send
+                                    + ::core::marker::Sync
+                            > {
+                                err.into()
+                            }
+                        )?;
                         (decoded, &sequence[len..])
                     };
                     let #field_name = #field_value;
@@ -733,10 +1011,6 @@ impl BFieldCodecDeriveBuilder {
         }
 
         let num_variants = variants.len();
-        if num_variants == 0 {
-            self.static_length_body = quote! {::core::option::Option::Some(0)};
-            return;
-        }
 
         // some variants have associated data
         // if all variants encode to the same length, the length is statically known anyway
@@ -858,12 +1132,14 @@ impl BFieldCodecErrorEnumBuilder {
         }
     }
 
-    fn build(&mut self, derive_type: BFieldCodecDeriveType) {
+    fn build(&mut self, derive_type: BFieldCodecDeriveType, has_length_capped_fields: bool) {
         match derive_type {
             BFieldCodecDeriveType::UnitStruct => self.set_up_unit_struct_errors(),
             BFieldCodecDeriveType::StructWithNamedFields
-            | BFieldCodecDeriveType::StructWithUnnamedFields => self.set_up_struct_errors(),
-            BFieldCodecDeriveType::Enum => self.set_up_enum_errors(),
+            | BFieldCodecDeriveType::StructWithUnnamedFields => {
+                self.set_up_struct_errors(has_length_capped_fields)
+            }
+            BFieldCodecDeriveType::Enum => self.set_up_enum_errors(has_length_capped_fields),
         }
     }
 
@@ -872,18 +1148,24 @@ impl BFieldCodecErrorEnumBuilder {
         self.register_error_inner_decoding_failure();
     }
 
-    fn set_up_struct_errors(&mut self) {
+    fn set_up_struct_errors(&mut self, has_length_capped_fields: bool) {
         self.register_error_sequence_empty();
         self.register_error_sequence_empty_for_field();
         self.register_error_sequence_too_short_for_field();
+        if has_length_capped_fields {
+            self.register_error_length_exceeds_maximum_for_field();
+        }
         self.register_error_sequence_too_long();
         self.register_error_inner_decoding_failure();
     }
 
-    fn set_up_enum_errors(&mut self) {
+    fn set_up_enum_errors(&mut self, has_length_capped_fields: bool) {
         self.register_error_sequence_empty();
         self.register_error_sequence_empty_for_variant();
         self.register_error_sequence_too_short_for_variant();
+        if has_length_capped_fields {
+            self.register_error_length_exceeds_maximum_for_variant();
+        }
         self.register_error_sequence_too_long();
         self.register_error_invalid_discriminant();
         self.register_error_inner_decoding_failure();
@@ -990,6 +1272,27 @@ impl BFieldCodecErrorEnumBuilder {
         );
     }
 
+    fn register_error_length_exceeds_maximum_for_field(&mut self) {
+        let name = self.name.to_string();
+
+        let variant_name = quote::format_ident!("LengthExceedsMaximumForField");
+        let variant_type = quote! { #variant_name(String, usize) };
+        let display_match_arm = quote! {
+            Self::#variant_name(field_name, length) => ::core::write!(
+                f,
+                "cannot decode {}, field {field_name}: length {length} exceeds maximum",
+                #name,
+            )
+        };
+
+        self.register_error(
+            "length_exceeds_max_for_field",
+            variant_name,
+            variant_type,
+            display_match_arm,
+        );
+    }
+
     fn register_error_sequence_empty_for_variant(&mut self) {
         let name = self.name.to_string();
 
@@ -1032,6 +1335,28 @@ impl BFieldCodecErrorEnumBuilder {
         );
     }
 
+    fn register_error_length_exceeds_maximum_for_variant(&mut self) {
+        let name = self.name.to_string();
+
+        let variant_name = quote::format_ident!("LengthExceedsMaximumForVariant");
+        let variant_type = quote! { #variant_name(usize, usize, usize) };
+        let display_match_arm = quote! {
+            Self::#variant_name(variant_id, field_id, length) => ::core::write!(
+                f,
+                "cannot decode {}, variant {variant_id}, field {field_id}: \
+                length {length} exceeds maximum",
+                #name,
+            )
+        };
+
+        self.register_error(
+            "length_exceeds_max_for_variant",
+            variant_name,
+            variant_type,
+            display_match_arm,
+        );
+    }
+
     fn register_error_invalid_discriminant(&mut self) {
         let name = self.name.to_string();
 
@@ -1100,6 +1425,11 @@ impl BFieldCodecErrorEnumBuilder {
         self.global_identifier(&error.variant_name)
     }
 
+    fn length_exceeds_maximum_for_field(&self) -> TokenStream {
+        let error = self.errors.get("length_exceeds_max_for_field").unwrap();
+        self.global_identifier(&error.variant_name)
+    }
+
     fn sequence_empty_for_variant(&self) -> TokenStream {
         let error = self.errors.get("seq_empty_for_variant").unwrap();
         self.global_identifier(&error.variant_name)
@@ -1110,6 +1440,11 @@ impl BFieldCodecErrorEnumBuilder {
         self.global_identifier(&error.variant_name)
     }
 
+    fn length_exceeds_maximum_for_variant(&self) -> TokenStream {
+        let error = self.errors.get("length_exceeds_max_for_variant").unwrap();
+        self.global_identifier(&error.variant_name)
+    }
+
     fn invalid_discriminant(&self) -> TokenStream {
         let error = self.errors.get("invalid_discriminant").unwrap();
         self.global_identifier(&error.variant_name)
@@ -1176,4 +1511,210 @@ mod tests {
         };
         let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
     }
+
+    #[test]
+    #[should_panic(expected = "enum `Never` without variants: the type is uninhabited")]
+    fn macro_rejects_enum_without_variants() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            enum Never {}
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn enum_with_fewer_variants_than_field_modulus_is_accepted() {
+        let name = quote::format_ident!("Huge");
+        let max_num_variants = 0xffff_ffff_0000_0000_u64 as usize;
+        BFieldCodecDeriveBuilder::ensure_variant_count_is_below_field_modulus(
+            &name,
+            max_num_variants,
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "the number of variants must be smaller than the field modulus")]
+    fn macro_rejects_enum_with_as_many_variants_as_field_modulus() {
+        let name = quote::format_ident!("Huge");
+        let num_variants = 0xffff_ffff_0000_0001_u64 as usize;
+        BFieldCodecDeriveBuilder::ensure_variant_count_is_below_field_modulus(&name, num_variants);
+    }
+
+    #[test]
+    fn vec_element_type_is_recognized_only_for_std_vec() {
+        let element_type = |field_type: Type| {
+            BFieldCodecDeriveBuilder::vec_element_type(&field_type)
+                .map(|t| quote::quote!(#t).to_string())
+        };
+        let expected = Some(quote::quote!(BFieldElement).to_string());
+
+        assert_eq!(expected, element_type(parse_quote!(Vec<BFieldElement>)));
+        assert_eq!(
+            expected,
+            element_type(parse_quote!(std::vec::Vec<BFieldElement>))
+        );
+        assert_eq!(
+            expected,
+            element_type(parse_quote!(::alloc::vec::Vec<BFieldElement>))
+        );
+        assert_eq!(None, element_type(parse_quote!(::Vec<BFieldElement>)));
+        assert_eq!(
+            None,
+            element_type(parse_quote!(my_crate::Vec<BFieldElement>))
+        );
+        assert_eq!(None, element_type(parse_quote!(VecDeque<BFieldElement>)));
+        assert_eq!(None, element_type(parse_quote!([BFieldElement; 3])));
+    }
+
+    #[test]
+    fn generics_only_in_phantom_data_receive_no_trait_bound() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct Tagged<T, U> {
+                value: U,
+                _marker: PhantomData<T>,
+                _other_marker: ::std::marker::PhantomData<U>,
+            }
+        };
+        let mut builder = BFieldCodecDeriveBuilder::new(ast);
+        builder.add_trait_bounds_to_generics();
+
+        let bounded_generics = builder
+            .generics
+            .type_params()
+            .filter(|param| !param.bounds.is_empty())
+            .map(|param| param.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["U".to_string()], bounded_generics);
+    }
+
+    #[test]
+    fn macro_compiles_when_expanding_struct_with_ignored_conditionally_compiled_field() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct StructWithConditionalField {
+                a: u64,
+                #[cfg(feature = "some_feature")]
+                #[bfield_codec(ignore)]
+                b: u64,
+            }
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "field `b` is conditionally compiled")]
+    fn macro_rejects_struct_with_included_conditionally_compiled_field() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct StructWithConditionalField {
+                a: u64,
+                #[cfg(feature = "some_feature")]
+                b: u64,
+            }
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
+
+    #[test]
+    fn field_attributes_parse_max_length() {
+        let field: Field = parse_quote! {
+            #[bfield_codec(max_length = 1000)]
+            a: Vec<u64>
+        };
+        let field_attributes = BFieldCodecDeriveBuilder::parse_field_attributes(&field, "a");
+        assert!(!field_attributes.is_ignored);
+        assert_eq!(Some(1000), field_attributes.max_length);
+    }
+
+    #[test]
+    fn macro_compiles_when_expanding_types_with_length_capped_fields() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct Capped {
+                #[bfield_codec(max_length = 10)]
+                a: Vec<Vec<u64>>,
+            }
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct CappedTuple(#[bfield_codec(max_length = 10)] Vec<u64>);
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            enum CappedEnum {
+                A(#[bfield_codec(max_length = 10)] Vec<u64>),
+            }
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
+
+    #[test]
+    fn error_enum_has_length_variants_only_if_some_field_is_length_capped() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct Uncapped {
+                a: Vec<u64>,
+            }
+        };
+        let mut builder = BFieldCodecDeriveBuilder::new(ast);
+        assert!(!builder.has_length_capped_fields());
+        builder.error_builder.build(builder.derive_type, false);
+        assert!(!builder
+            .error_builder
+            .errors
+            .contains_key("length_exceeds_max_for_field"));
+
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            enum CappedEnum {
+                A(u64),
+                B(#[bfield_codec(max_length = 10)] Vec<u64>),
+            }
+        };
+        let builder = BFieldCodecDeriveBuilder::new(ast);
+        assert!(builder.has_length_capped_fields());
+    }
+
+    #[test]
+    #[should_panic(expected = "field `a` is ignored and cannot have a maximum length")]
+    fn macro_rejects_ignored_field_with_max_length() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct Capped {
+                #[bfield_codec(ignore, max_length = 10)]
+                a: Vec<u64>,
+            }
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid `bfield_codec` attribute for field `a`")]
+    fn macro_rejects_non_integer_max_length() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct Capped {
+                #[bfield_codec(max_length = "ten")]
+                a: Vec<u64>,
+            }
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "field `1` is conditionally compiled")]
+    fn macro_rejects_tuple_struct_with_conditionally_compiled_field() {
+        let ast = parse_quote! {
+            #[derive(BFieldCodec)]
+            struct TupleStructWithConditionalField(u64, #[cfg(feature = "some_feature")] u64);
+        };
+        let _rust_code = BFieldCodecDeriveBuilder::new(ast).build();
+    }
 }
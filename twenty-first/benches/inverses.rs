@@ -2,7 +2,9 @@ use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use itertools::Itertools;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::other::random_elements;
+use twenty_first::shared_math::traits::FiniteField;
 use twenty_first::shared_math::traits::Inverse;
+use twenty_first::shared_math::x_field_element::XFieldElement;
 
 /// Run with `cargo criterion --bench inverse`
 fn inverse(c: &mut Criterion) {
@@ -10,15 +12,37 @@ fn inverse(c: &mut Criterion) {
     group.sample_size(10); // runs
     let count = 1024 * 1024; // count of elements to be inversed per run
 
-    let rnd_elems: Vec<BFieldElement> = random_elements(count);
+    // counts of elements to be batch inversed per run
+    for batch_count in [1024, count] {
+        let rnd_elems: Vec<BFieldElement> = random_elements(batch_count);
 
-    let inverse = BenchmarkId::new("Inverse", 0);
-    group.bench_function(inverse, |bencher| {
+        let inverse = BenchmarkId::new("Inverse", batch_count);
+        group.bench_with_input(inverse, &rnd_elems, |bencher, rnd_elems| {
+            bencher.iter(|| {
+                rnd_elems.iter().map(|x| x.inverse()).collect_vec();
+            });
+        });
+
+        let batch_inverse = BenchmarkId::new("Batch Inverse", batch_count);
+        group.bench_with_input(batch_inverse, &rnd_elems, |bencher, rnd_elems| {
+            bencher.iter(|| BFieldElement::batch_inverse(rnd_elems));
+        });
+    }
+
+    let rnd_xfes: Vec<XFieldElement> = random_elements(count);
+
+    let xfe_inverse = BenchmarkId::new("XFE Inverse", count);
+    group.bench_function(xfe_inverse, |bencher| {
         bencher.iter(|| {
-            rnd_elems.iter().map(|x| x.inverse()).collect_vec();
+            rnd_xfes.iter().map(|x| x.inverse()).collect_vec();
         });
     });
 
+    let xfe_batch_inverse = BenchmarkId::new("XFE Batch Inverse", count);
+    group.bench_function(xfe_batch_inverse, |bencher| {
+        bencher.iter(|| XFieldElement::batch_inverse(&rnd_xfes));
+    });
+
     group.finish();
 }
 
@@ -1,9 +1,10 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
 use std::time::Duration;
 use twenty_first::shared_math::digest::Digest;
 use twenty_first::shared_math::other::random_elements;
 use twenty_first::shared_math::tip5::Tip5;
-use twenty_first::util_types::merkle_tree::{CpuParallel, MerkleTree};
+use twenty_first::util_types::merkle_tree::{BatchedCpu, CpuParallel, MerkleTree, ReusableCpu};
 use twenty_first::util_types::merkle_tree_maker::MerkleTreeMaker;
 
 fn merkle_tree(c: &mut Criterion) {
@@ -20,7 +21,59 @@ fn merkle_tree(c: &mut Criterion) {
     group.bench_function(BenchmarkId::new("merkle_tree", size), |bencher| {
         bencher.iter(|| -> MerkleTree<H> { CpuParallel::from_digests(&elements[..]).unwrap() });
     });
+
+    group.bench_function(BenchmarkId::new("batched", size), |bencher| {
+        bencher.iter(|| -> MerkleTree<H> { BatchedCpu::from_digests(&elements[..]).unwrap() });
+    });
+}
+
+fn leaves_in_range(c: &mut Criterion) {
+    type H = Tip5;
+    let mut group = c.benchmark_group("leaves_in_range");
+
+    let size = 1 << 20;
+    let elements: Vec<Digest> = random_elements(size);
+    let tree: MerkleTree<H> = CpuParallel::from_digests(&elements).unwrap();
+    group.sample_size(10);
+
+    group.bench_function(BenchmarkId::new("per_index", size), |bencher| {
+        bencher.iter(|| (0..size).map(|i| tree.leaf(i).unwrap()).collect::<Vec<_>>());
+    });
+
+    group.bench_function(BenchmarkId::new("range", size), |bencher| {
+        bencher.iter(|| tree.leaves_in_range(0..size));
+    });
+}
+
+fn many_small_trees(c: &mut Criterion) {
+    type H = Tip5;
+    let mut group = c.benchmark_group("many_small_trees");
+
+    let num_trees = 10_000;
+    let size = 1 << 10;
+    let elements: Vec<Digest> = random_elements(size);
+    group.sample_size(10);
+
+    group.bench_function(BenchmarkId::new("fresh_allocation", num_trees), |bencher| {
+        bencher.iter(|| {
+            for _ in 0..num_trees {
+                let tree: MerkleTree<H> = CpuParallel::from_digests(&elements).unwrap();
+                black_box(tree.root());
+            }
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("reused_buffer", num_trees), |bencher| {
+        let mut maker = ReusableCpu::new();
+        bencher.iter(|| {
+            for _ in 0..num_trees {
+                let tree: MerkleTree<H> = maker.from_digests_into(&elements).unwrap();
+                black_box(tree.root());
+                maker.recycle(tree);
+            }
+        });
+    });
 }
 
-criterion_group!(benches, merkle_tree);
+criterion_group!(benches, merkle_tree, leaves_in_range, many_small_trees);
 criterion_main!(benches);
@@ -12,7 +12,8 @@ criterion_main!(merkle_tree_authenticate);
 criterion_group!(
     merkle_tree_authenticate,
     gen_auth_structure,
-    verify_auth_structure
+    verify_auth_structure,
+    verify_wide_auth_structure
 );
 
 fn gen_auth_structure(c: &mut Criterion) {
@@ -41,6 +42,23 @@ fn verify_auth_structure(c: &mut Criterion) {
     });
 }
 
+fn verify_wide_auth_structure(c: &mut Criterion) {
+    let mut sampler = MerkleTreeSampler {
+        tree_height: 20,
+        num_opened_indices: 4000,
+        ..Default::default()
+    };
+    let tree = sampler.tree();
+
+    c.bench_function("verify_wide_auth_structure", |bencher| {
+        bencher.iter_batched(
+            || sampler.proof(&tree),
+            |proof| proof.verify(tree.root()),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct MerkleTreeSampler {
     rng: StdRng,
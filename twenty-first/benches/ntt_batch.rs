@@ -0,0 +1,37 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::ntt::{ntt, ntt_batch};
+use twenty_first::shared_math::other::random_elements;
+use twenty_first::shared_math::traits::PrimitiveRootOfUnity;
+
+/// Run with `cargo criterion --bench ntt_batch`
+fn ntt_batch_vs_loop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ntt_batch");
+    group.sample_size(10);
+
+    let num_columns = 4096;
+    let log2_of_size = 16;
+    let size = 1 << log2_of_size;
+    let omega = BFieldElement::primitive_root_of_unity(size as u64).unwrap();
+    let mut columns: Vec<Vec<BFieldElement>> =
+        (0..num_columns).map(|_| random_elements(size)).collect();
+
+    let loop_id = BenchmarkId::new("loop", log2_of_size);
+    group.bench_function(loop_id, |bencher| {
+        bencher.iter(|| {
+            for column in columns.iter_mut() {
+                ntt(column, omega, log2_of_size);
+            }
+        });
+    });
+
+    let batch_id = BenchmarkId::new("batch", log2_of_size);
+    group.bench_function(batch_id, |bencher| {
+        bencher.iter(|| ntt_batch(&mut columns, omega, log2_of_size));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, ntt_batch_vs_loop);
+criterion_main!(benches);
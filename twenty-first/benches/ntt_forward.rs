@@ -4,6 +4,7 @@ use criterion::{
 };
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::ntt::ntt;
+use twenty_first::shared_math::ntt::ntt_parallel;
 use twenty_first::shared_math::other::random_elements;
 use twenty_first::shared_math::traits::PrimitiveRootOfUnity;
 use twenty_first::shared_math::x_field_element::XFieldElement;
@@ -22,6 +23,15 @@ fn chu_ntt_forward(c: &mut Criterion) {
         );
     }
 
+    // Benchmarking parallel forward ntt on BFieldElements
+    for &log2_of_size in log2_of_sizes.iter() {
+        bfield_parallel_benchmark(
+            &mut group,
+            BenchmarkId::new("bfield_parallel", log2_of_size),
+            log2_of_size,
+        );
+    }
+
     // Benchmarking forward ntt on XFieldElements
     for &log2_of_size in log2_of_sizes.iter() {
         xfield_benchmark(
@@ -50,6 +60,22 @@ fn bfield_benchmark(
     group.sample_size(10);
 }
 
+fn bfield_parallel_benchmark(
+    group: &mut BenchmarkGroup<WallTime>,
+    bench_id: BenchmarkId,
+    log2_of_size: usize,
+) {
+    let size: usize = 1 << log2_of_size;
+    let mut xs: Vec<BFieldElement> = random_elements(size);
+    let omega = BFieldElement::primitive_root_of_unity(size as u64).unwrap();
+
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(bench_id, &size, |b, _| {
+        b.iter(|| ntt_parallel::<BFieldElement>(&mut xs, omega, log2_of_size as u32))
+    });
+    group.sample_size(10);
+}
+
 fn xfield_benchmark(
     group: &mut BenchmarkGroup<WallTime>,
     bench_id: BenchmarkId,
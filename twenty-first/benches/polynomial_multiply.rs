@@ -0,0 +1,31 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::other::random_elements;
+use twenty_first::shared_math::polynomial::Polynomial;
+
+criterion_main!(benches);
+criterion_group!(benches, polynomial_multiply);
+
+/// Run with `cargo criterion --bench polynomial_multiply`
+fn polynomial_multiply(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polynomial_multiply");
+    group.sample_size(10);
+
+    let log2_of_degree = 16;
+    let num_coefficients = (1 << log2_of_degree) + 1;
+    let a = Polynomial::<BFieldElement>::new(random_elements(num_coefficients));
+    let b = Polynomial::<BFieldElement>::new(random_elements(num_coefficients));
+
+    let naive_id = BenchmarkId::new("naive", log2_of_degree);
+    group.bench_function(naive_id, |bencher| {
+        bencher.iter(|| a.clone().multiply(b.clone()))
+    });
+
+    let fast_id = BenchmarkId::new("fast", log2_of_degree);
+    group.bench_function(fast_id, |bencher| bencher.iter(|| a.fast_multiply(&b)));
+
+    group.finish();
+}
@@ -0,0 +1,43 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::other::random_elements;
+
+fn prefix_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("prefix_scan");
+
+    let size = 1 << 22;
+    let elements: Vec<BFieldElement> = random_elements(size);
+    group.throughput(Throughput::Elements(size as u64));
+
+    group.bench_function(BenchmarkId::new("serial_products", size), |b| {
+        b.iter(|| {
+            let mut accumulator = BFieldElement::new(1);
+            elements
+                .iter()
+                .map(|&element| {
+                    accumulator *= element;
+                    accumulator
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("parallel_products", size), |b| {
+        b.iter(|| BFieldElement::prefix_products(&elements))
+    });
+
+    group.bench_function(BenchmarkId::new("parallel_sums", size), |b| {
+        b.iter(|| BFieldElement::prefix_sums(&elements))
+    });
+
+    group.sample_size(10);
+    group.finish();
+}
+
+criterion_group!(benches, prefix_scan);
+criterion_main!(benches);
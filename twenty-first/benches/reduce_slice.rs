@@ -0,0 +1,41 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::other::random_elements;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+fn reduce_slice(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reduce_slice");
+
+    let size = 1 << 22;
+    let bfes: Vec<BFieldElement> = random_elements(size);
+    let xfes: Vec<XFieldElement> = random_elements(size);
+    group.throughput(Throughput::Elements(size as u64));
+
+    group.bench_function(BenchmarkId::new("BFieldElement", size), |b| {
+        b.iter_batched_ref(
+            || bfes.clone(),
+            |elements| BFieldElement::reduce_slice_parallel(elements),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function(BenchmarkId::new("XFieldElement", size), |b| {
+        b.iter_batched_ref(
+            || xfes.clone(),
+            |elements| XFieldElement::reduce_slice_parallel(elements),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.sample_size(10);
+    group.finish();
+}
+
+criterion_group!(benches, reduce_slice);
+criterion_main!(benches);
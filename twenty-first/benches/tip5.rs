@@ -64,5 +64,33 @@ fn bench_parallel(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_10, bench_pair, bench_varlen, bench_parallel);
+fn bench_varlen_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tip5/hash_varlen_batch");
+
+    let size = 1_000_000;
+    group.sample_size(10);
+    let inputs: Vec<Vec<BFieldElement>> = (0..size).map(|_| random_elements(8)).collect();
+
+    group.bench_function(BenchmarkId::new("Tip5 / Serial", size), |bencher| {
+        bencher.iter(|| {
+            inputs
+                .iter()
+                .map(|input| Tip5::hash_varlen(input))
+                .collect::<Vec<_>>()
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("Tip5 / Batch", size), |bencher| {
+        bencher.iter(|| Tip5::hash_varlen_batch(&inputs));
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_10,
+    bench_pair,
+    bench_varlen,
+    bench_parallel,
+    bench_varlen_batch
+);
 criterion_main!(benches);
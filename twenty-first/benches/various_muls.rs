@@ -128,5 +128,25 @@ fn xfe_bfe_mul(group: &mut BenchmarkGroup<WallTime>, bench_id: BenchmarkId, size
     group.sample_size(10);
 }
 
-criterion_group!(benches, unsigned_mul);
+fn pointwise_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pointwise_mul");
+
+    let size = 1 << 20;
+    let xs: Vec<BFieldElement> = random_elements(size);
+    let ys: Vec<BFieldElement> = random_elements(size);
+    group.throughput(Throughput::Elements(size as u64));
+
+    group.bench_function(BenchmarkId::new("zip", size), |b| {
+        b.iter(|| xs.iter().zip(&ys).map(|(&x, &y)| x * y).collect::<Vec<_>>())
+    });
+
+    group.bench_function(BenchmarkId::new("pointwise_mul", size), |b| {
+        b.iter(|| BFieldElement::pointwise_mul(&xs, &ys))
+    });
+
+    group.sample_size(10);
+    group.finish();
+}
+
+criterion_group!(benches, unsigned_mul, pointwise_mul);
 criterion_main!(benches);
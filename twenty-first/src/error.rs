@@ -3,6 +3,8 @@ use thiserror::Error;
 
 use crate::prelude::tip5::DIGEST_LENGTH;
 use crate::prelude::x_field_element::EXTENSION_DEGREE;
+use crate::prelude::BFieldElement;
+use crate::prelude::Digest;
 pub use crate::shared_math::bfield_codec::BFieldCodecError;
 pub use crate::util_types::merkle_tree::MerkleTreeError;
 
@@ -11,6 +13,9 @@ pub use crate::util_types::merkle_tree::MerkleTreeError;
 pub enum ParseBFieldElementError {
     #[error("invalid `u64`")]
     ParseU64Error(#[source] <u64 as FromStr>::Err),
+
+    #[error("non-canonical {0} >= {p}", p = BFieldElement::P)]
+    NotCanonical(u64),
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Error)]
@@ -41,4 +46,19 @@ pub enum TryFromDigestError {
 
     #[error("overflow converting to Digest")]
     Overflow,
+
+    #[error("expected {len} hex characters for digest, but got {0}", len = 2 * Digest::BYTES)]
+    InvalidHexLength(usize),
+
+    #[error("invalid hex character {0:?}")]
+    InvalidHexCharacter(char),
+
+    #[error("invalid bech32m encoding")]
+    InvalidBech32(#[from] bech32::Error),
+
+    #[error("expected bech32m encoding, but got bech32")]
+    InvalidBech32Variant,
+
+    #[error("expected {len} bytes for digest, but got {0}", len = Digest::BYTES)]
+    InvalidByteLength(usize),
 }
@@ -10,5 +10,6 @@ pub mod ntt;
 pub mod other;
 pub mod polynomial;
 pub mod tip5;
+pub mod tip5_rng;
 pub mod traits;
 pub mod x_field_element;
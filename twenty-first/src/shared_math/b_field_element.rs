@@ -2,6 +2,7 @@ use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::fmt;
 use std::hash::Hash;
+use std::iter::Product;
 use std::iter::Sum;
 use std::num::TryFromIntError;
 use std::ops::Add;
@@ -23,10 +24,12 @@ use phf::phf_map;
 use rand::Rng;
 use rand_distr::Distribution;
 use rand_distr::Standard;
+use rayon::prelude::*;
 use serde::Deserialize;
 use serde::Deserializer;
 use serde::Serialize;
 use serde::Serializer;
+use zeroize::Zeroize;
 
 use crate::error::ParseBFieldElementError;
 use crate::shared_math::traits::CyclicGroupGenerator;
@@ -108,27 +111,70 @@ impl<'a> Arbitrary<'a> for BFieldElement {
     }
 }
 
+/// Serializes the canonical value, independent of the internal representation: for
+/// human-readable formats like JSON as a `u64`, for binary formats as the 8
+/// [canonical bytes](BFieldElement::to_canonical_bytes).
 impl Serialize for BFieldElement {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        self.value().serialize(serializer)
+        if serializer.is_human_readable() {
+            self.value().serialize(serializer)
+        } else {
+            self.to_canonical_bytes().serialize(serializer)
+        }
     }
 }
 
+/// Inverse of the [`Serialize`] implementation. Rejects non-canonical values, _i.e._, values
+/// `>= P`, guaranteeing that every element has exactly one valid serialization.
 impl<'de> Deserialize<'de> for BFieldElement {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Ok(Self::new(u64::deserialize(deserializer)?))
+        let element = if deserializer.is_human_readable() {
+            Self::try_new(u64::deserialize(deserializer)?)
+        } else {
+            Self::try_from_canonical_bytes(&<[u8; 8]>::deserialize(deserializer)?)
+        };
+        element.map_err(serde::de::Error::custom)
     }
 }
 
 pub const BFIELD_ZERO: BFieldElement = BFieldElement::new(0);
 pub const BFIELD_ONE: BFieldElement = BFieldElement::new(1);
 
+/// The number of arithmetic operations on [`BFieldElement`]s performed by the current thread.
+/// Only available with feature `debug-trace`. Intended for profiling; the results of arithmetic
+/// are not affected.
+///
+/// Only the operators are counted. For example, negation counts as a subtraction, while
+/// specialized functions like [`BFieldElement::pointwise_mul`] are not counted.
+#[cfg(feature = "debug-trace")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpCounts {
+    pub additions: u64,
+    pub subtractions: u64,
+    pub multiplications: u64,
+}
+
+#[cfg(feature = "debug-trace")]
+thread_local! {
+    static OP_COUNTS: std::cell::Cell<OpCounts> = std::cell::Cell::new(OpCounts::default());
+}
+
+#[cfg(feature = "debug-trace")]
+#[inline(always)]
+fn record_operation(record: fn(&mut OpCounts)) {
+    OP_COUNTS.with(|cell| {
+        let mut counts = cell.get();
+        record(&mut counts);
+        cell.set(counts);
+    });
+}
+
 impl Sum for BFieldElement {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.reduce(|a, b| a + b)
@@ -136,9 +182,43 @@ impl Sum for BFieldElement {
     }
 }
 
+impl<'a> Sum<&'a BFieldElement> for BFieldElement {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for BFieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a * b).unwrap_or_else(BFieldElement::one)
+    }
+}
+
+impl<'a> Product<&'a BFieldElement> for BFieldElement {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
 impl BFieldElement {
     pub const BYTES: usize = 8;
 
+    /// The number of arithmetic operations performed by the current thread since it started or
+    /// since the last call to [`reset_op_counts`](Self::reset_op_counts).
+    #[cfg(feature = "debug-trace")]
+    pub fn op_counts() -> OpCounts {
+        OP_COUNTS.with(|cell| cell.get())
+    }
+
+    #[cfg(feature = "debug-trace")]
+    pub fn reset_op_counts() {
+        OP_COUNTS.with(|cell| cell.set(OpCounts::default()));
+    }
+
+    /// The number of elements per task in parallel operations over slices, like
+    /// [`reduce_slice_parallel`](Self::reduce_slice_parallel).
+    pub(crate) const PARALLEL_CHUNK_SIZE: usize = 1 << 14;
+
     /// The base field's prime, _i.e._, 2^64 - 2^32 + 1.
     pub const P: u64 = 0xffff_ffff_0000_0001u64;
     pub const MAX: u64 = Self::P - 1;
@@ -151,6 +231,14 @@ impl BFieldElement {
         Self(Self::montyred((value as u128) * (Self::R2 as u128)))
     }
 
+    /// Like [`new`](Self::new), but rejects any `value >= P` instead of reducing it.
+    pub const fn try_new(value: u64) -> Result<Self, ParseBFieldElementError> {
+        if value >= Self::P {
+            return Err(ParseBFieldElementError::NotCanonical(value));
+        }
+        Ok(Self::new(value))
+    }
+
     #[inline]
     pub const fn value(&self) -> u64 {
         self.canonical_representation()
@@ -201,6 +289,56 @@ impl BFieldElement {
         *self -= Self::one();
     }
 
+    /// The Legendre symbol: 0 for zero, 1 for non-zero squares, and -1 for non-squares.
+    pub fn legendre_symbol(&self) -> i8 {
+        let euler_criterion = self.mod_pow((Self::P - 1) / 2);
+        if euler_criterion.is_zero() {
+            0
+        } else if euler_criterion.is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// A square root of `self`, or `None` if `self` is not a square.
+    ///
+    /// Uses Tonelli–Shanks, specialized to `P - 1 = 2^32 · (2^32 - 1)`. Which of the two roots
+    /// is returned is unspecified.
+    pub fn sqrt(&self) -> Option<Self> {
+        const TWO_ADICITY: u32 = 32;
+        const ODD_PART: u64 = (BFieldElement::P - 1) >> TWO_ADICITY;
+
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        if self.legendre_symbol() != 1 {
+            return None;
+        }
+
+        // The generator is a non-square, so `c` generates the subgroup of order 2^32.
+        let mut c = Self::generator().mod_pow(ODD_PART);
+        let mut t = self.mod_pow(ODD_PART);
+        let mut root = self.mod_pow((ODD_PART + 1) / 2);
+        let mut m = TWO_ADICITY;
+        while !t.is_one() {
+            let mut i = 1;
+            let mut t_pow = t.square();
+            while !t_pow.is_one() {
+                t_pow = t_pow.square();
+                i += 1;
+            }
+
+            let b = c.mod_pow(1 << (m - i - 1));
+            m = i;
+            c = b.square();
+            t *= c;
+            root *= b;
+        }
+
+        Some(root)
+    }
+
     #[inline]
     const fn canonical_representation(&self) -> u64 {
         Self::montyred(self.0 as u128)
@@ -247,6 +385,143 @@ impl BFieldElement {
         r.wrapping_sub((1 + !Self::P) * c as u64)
     }
 
+    /// The same element, with its internal representation in the range `[0, P)`.
+    ///
+    /// Arithmetic always produces such reduced representations; only elements constructed from
+    /// a raw representation, _e.g._, through [`from_raw_u64`](Self::from_raw_u64), might not be
+    /// reduced.
+    #[inline]
+    pub(crate) const fn reduced(self) -> Self {
+        if self.0 >= Self::P {
+            Self(self.0 - Self::P)
+        } else {
+            self
+        }
+    }
+
+    /// Reduce the internal representation of all elements in place, using multiple threads. See
+    /// [`from_raw_u64`](Self::from_raw_u64) for a way to get non-reduced elements. Elements
+    /// that are already reduced are not changed.
+    pub fn reduce_slice_parallel(elements: &mut [Self]) {
+        elements
+            .par_chunks_mut(Self::PARALLEL_CHUNK_SIZE)
+            .for_each(|chunk| chunk.iter_mut().for_each(|e| *e = e.reduced()));
+    }
+
+    /// The first `count` powers of `base`, _i.e._, `[1, base, base^2, …, base^(count-1)]`,
+    /// computed by iterated multiplication.
+    pub fn powers(base: Self, count: usize) -> Vec<Self> {
+        let mut powers = Vec::with_capacity(count);
+        let mut power = BFIELD_ONE;
+        for _ in 0..count {
+            powers.push(power);
+            power *= base;
+        }
+        powers
+    }
+
+    /// Like [`powers`](Self::powers), but using multiple threads. Every thread computes a
+    /// contiguous chunk of powers, starting from an explicitly computed power of `base`.
+    pub fn powers_parallel(base: Self, count: usize) -> Vec<Self> {
+        let mut powers = vec![BFIELD_ZERO; count];
+        powers
+            .par_chunks_mut(Self::PARALLEL_CHUNK_SIZE)
+            .enumerate()
+            .for_each(|(chunk_index, chunk)| {
+                let exponent = chunk_index * Self::PARALLEL_CHUNK_SIZE;
+                let mut power = base.mod_pow(exponent as u64);
+                for element in chunk {
+                    *element = power;
+                    power *= base;
+                }
+            });
+        powers
+    }
+
+    /// The running sums of the given elements: the `i`th output is the sum of the first `i + 1`
+    /// inputs. Computed using multiple threads.
+    pub fn prefix_sums(elements: &[Self]) -> Vec<Self> {
+        Self::prefix_scan_parallel(elements, BFIELD_ZERO, |a, b| a + b)
+    }
+
+    /// The running products of the given elements: the `i`th output is the product of the first
+    /// `i + 1` inputs. Computed using multiple threads.
+    pub fn prefix_products(elements: &[Self]) -> Vec<Self> {
+        Self::prefix_scan_parallel(elements, BFIELD_ONE, |a, b| a * b)
+    }
+
+    /// Inclusive scan using the given associative operation. Every thread first scans one
+    /// contiguous chunk of the input. Then, the chunks' totals are scanned sequentially. Finally,
+    /// every chunk's elements are offset by the total of all preceding chunks, again in parallel.
+    /// The total work is linear in the number of elements.
+    fn prefix_scan_parallel<F>(elements: &[Self], identity: Self, operation: F) -> Vec<Self>
+    where
+        F: Fn(Self, Self) -> Self + Sync,
+    {
+        let mut scan = elements.to_vec();
+        scan.par_chunks_mut(Self::PARALLEL_CHUNK_SIZE)
+            .for_each(|chunk| {
+                for i in 1..chunk.len() {
+                    chunk[i] = operation(chunk[i - 1], chunk[i]);
+                }
+            });
+
+        let chunk_offsets = scan
+            .chunks(Self::PARALLEL_CHUNK_SIZE)
+            .scan(identity, |total, chunk| {
+                let offset = *total;
+                *total = operation(offset, chunk[chunk.len() - 1]);
+                Some(offset)
+            })
+            .collect::<Vec<_>>();
+
+        scan.par_chunks_mut(Self::PARALLEL_CHUNK_SIZE)
+            .zip(chunk_offsets)
+            .skip(1)
+            .for_each(|(chunk, offset)| {
+                for element in chunk {
+                    *element = operation(offset, *element);
+                }
+            });
+        scan
+    }
+
+    /// Element-wise product of two equally long slices.
+    ///
+    /// Equivalent to `a.iter().zip(b).map(|(&x, &y)| x * y)`, but processes fixed-size lanes
+    /// of known length, giving the compiler a chance to vectorize the Montgomery
+    /// reductions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn pointwise_mul(a: &[Self], b: &[Self]) -> Vec<Self> {
+        const LANES: usize = 8;
+
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "Pointwise multiplication requires slices of equal length."
+        );
+
+        let mut product = vec![Self::zero(); a.len()];
+        let mut product_chunks = product.chunks_exact_mut(LANES);
+        let mut a_chunks = a.chunks_exact(LANES);
+        let mut b_chunks = b.chunks_exact(LANES);
+        for ((out, lhs), rhs) in (&mut product_chunks).zip(&mut a_chunks).zip(&mut b_chunks) {
+            for (o, (l, r)) in out.iter_mut().zip(lhs.iter().zip(rhs)) {
+                *o = Self(Self::montyred((l.0 as u128) * (r.0 as u128)));
+            }
+        }
+
+        let remainders = a_chunks.remainder().iter().zip(b_chunks.remainder());
+        for (out, (&lhs, &rhs)) in product_chunks.into_remainder().iter_mut().zip(remainders) {
+            *out = lhs * rhs;
+        }
+
+        product
+    }
+
     /// Return the raw bytes or 8-bit chunks of the Montgomery
     /// representation, in little-endian byte order
     pub const fn raw_bytes(&self) -> [u8; 8] {
@@ -260,6 +535,19 @@ impl BFieldElement {
         Self(u64::from_le_bytes(*bytes))
     }
 
+    /// The canonical value, _i.e._, the integer in `[0, P)`, in little-endian byte order.
+    /// In contrast to [`raw_bytes`](Self::raw_bytes), this is independent of the internal
+    /// representation.
+    pub const fn to_canonical_bytes(&self) -> [u8; 8] {
+        self.canonical_representation().to_le_bytes()
+    }
+
+    /// Inverse of [`to_canonical_bytes`](Self::to_canonical_bytes). Rejects any encoding of
+    /// an integer `>= P`, guaranteeing that every element has exactly one valid encoding.
+    pub fn try_from_canonical_bytes(bytes: &[u8; 8]) -> Result<Self, ParseBFieldElementError> {
+        Self::try_new(u64::from_le_bytes(*bytes))
+    }
+
     /// Return the raw 16-bit chunks of the Montgomery
     /// representation, in little-endian chunk order
     pub const fn raw_u16s(&self) -> [u16; 4] {
@@ -299,6 +587,18 @@ impl BFieldElement {
     }
 }
 
+/// Overwrites the element with zero. Since zero's Montgomery representation is 0, the internal
+/// storage is cleared entirely.
+///
+/// `BFieldElement` is [`Copy`], which rules out implementing [`Drop`] and thus
+/// [`ZeroizeOnDrop`](zeroize::ZeroizeOnDrop). To wipe secrets on drop, use
+/// [`Zeroizing`](zeroize::Zeroizing), for example `Zeroizing<Vec<BFieldElement>>`.
+impl Zeroize for BFieldElement {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl Emojihash for BFieldElement {
     fn emojihash(&self) -> String {
         emojihash::hash(&self.canonical_representation().to_be_bytes())
@@ -308,26 +608,25 @@ impl Emojihash for BFieldElement {
     }
 }
 
+/// Prints the canonical value in decimal.
 impl fmt::Display for BFieldElement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let canonical_value = Self::canonical_representation(self);
-        let cutoff = 256;
-        if canonical_value >= Self::P - cutoff {
-            write!(f, "-{}", Self::P - canonical_value)
-        } else if canonical_value <= cutoff {
-            write!(f, "{canonical_value}")
-        } else {
-            write!(f, "{canonical_value:>020}")
-        }
+        write!(f, "{}", self.value())
     }
 }
 
+/// Parses a canonical value, given either in decimal or as `0x`-prefixed hexadecimal.
+/// Values greater than or equal to [`BFieldElement::P`] are rejected.
 impl FromStr for BFieldElement {
     type Err = ParseBFieldElementError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parsed = s.parse().map_err(Self::Err::ParseU64Error)?;
-        Ok(BFieldElement::new(parsed))
+        let parsed = match s.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => s.parse(),
+        };
+        let value = parsed.map_err(Self::Err::ParseU64Error)?;
+        Self::try_new(value)
     }
 }
 
@@ -513,6 +812,9 @@ impl Add for BFieldElement {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline(always)]
     fn add(self, rhs: Self) -> Self {
+        #[cfg(feature = "debug-trace")]
+        record_operation(|counts| counts.additions += 1);
+
         // Compute a + b = a - (p - b).
         let (x1, c1) = self.0.overflowing_sub(Self::P - rhs.0);
 
@@ -556,6 +858,9 @@ impl Mul for BFieldElement {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self {
+        #[cfg(feature = "debug-trace")]
+        record_operation(|counts| counts.multiplications += 1);
+
         Self(Self::montyred((self.0 as u128) * (rhs.0 as u128)))
     }
 }
@@ -575,6 +880,9 @@ impl Sub for BFieldElement {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn sub(self, rhs: Self) -> Self {
+        #[cfg(feature = "debug-trace")]
+        record_operation(|counts| counts.subtractions += 1);
+
         let (x1, c1) = self.0.overflowing_sub(rhs.0);
 
         // The following code is equivalent to the commented-out code below
@@ -612,6 +920,10 @@ impl ModPowU64 for BFieldElement {
 }
 
 impl PrimitiveRootOfUnity for BFieldElement {
+    /// A generator of the multiplicative subgroup of order `n`, for example, for use as `omega` in
+    /// the [NTT](crate::shared_math::ntt::ntt). Such a subgroup exists if `n` is a power of two
+    /// no larger than 2^32. For `n` 0 or 1, the root is 1. For all other `n`, the result is
+    /// `None`.
     fn primitive_root_of_unity(n: u64) -> Option<BFieldElement> {
         // Check if n is one of the values for which we have pre-calculated roots
         if PRIMITIVE_ROOTS.contains_key(&n) {
@@ -651,6 +963,156 @@ mod b_prime_field_element_test {
         type Strategy = BoxedStrategy<Self>;
     }
 
+    #[proptest]
+    fn sum_and_product_agree_with_folds(elements: Vec<BFieldElement>) {
+        let sum = elements
+            .iter()
+            .fold(BFieldElement::zero(), |acc, &e| acc + e);
+        let product = elements
+            .iter()
+            .fold(BFieldElement::one(), |acc, &e| acc * e);
+
+        prop_assert_eq!(sum, elements.iter().sum::<BFieldElement>());
+        prop_assert_eq!(sum, elements.iter().copied().sum::<BFieldElement>());
+        prop_assert_eq!(product, elements.iter().product::<BFieldElement>());
+        prop_assert_eq!(product, elements.into_iter().product::<BFieldElement>());
+    }
+
+    #[cfg(feature = "debug-trace")]
+    #[test]
+    fn arithmetic_operations_are_counted() {
+        BFieldElement::reset_op_counts();
+        let a = BFieldElement::new(3);
+        let b = BFieldElement::new(5);
+
+        let c = a + b;
+        let d = c * a;
+        let mut e = d - b;
+        e += a;
+        e *= b;
+        let _ = -e;
+
+        let expected = OpCounts {
+            additions: 2,
+            subtractions: 2,
+            multiplications: 2,
+        };
+        assert_eq!(expected, BFieldElement::op_counts());
+
+        BFieldElement::reset_op_counts();
+        assert_eq!(OpCounts::default(), BFieldElement::op_counts());
+    }
+
+    #[test]
+    fn sum_and_product_of_empty_iterator_are_identities() {
+        let no_elements: [BFieldElement; 0] = [];
+        assert!(no_elements.iter().sum::<BFieldElement>().is_zero());
+        assert!(no_elements.iter().product::<BFieldElement>().is_one());
+    }
+
+    #[proptest]
+    fn powers_agree_with_mod_pow(base: BFieldElement, #[strategy(0_usize..200)] count: usize) {
+        let powers = BFieldElement::powers(base, count);
+        prop_assert_eq!(count, powers.len());
+        for (exponent, power) in powers.into_iter().enumerate() {
+            prop_assert_eq!(base.mod_pow(exponent as u64), power);
+        }
+    }
+
+    #[proptest]
+    fn parallel_powers_agree_with_serial_powers(
+        base: BFieldElement,
+        #[strategy(0_usize..3 * BFieldElement::PARALLEL_CHUNK_SIZE)] count: usize,
+    ) {
+        let powers = BFieldElement::powers(base, count);
+        let parallel_powers = BFieldElement::powers_parallel(base, count);
+        prop_assert_eq!(powers, parallel_powers);
+    }
+
+    fn serial_prefix_scan(
+        elements: &[BFieldElement],
+        identity: BFieldElement,
+        operation: impl Fn(BFieldElement, BFieldElement) -> BFieldElement,
+    ) -> Vec<BFieldElement> {
+        let mut accumulator = identity;
+        let mut scan = Vec::with_capacity(elements.len());
+        for &element in elements {
+            accumulator = operation(accumulator, element);
+            scan.push(accumulator);
+        }
+        scan
+    }
+
+    #[proptest(cases = 20)]
+    fn prefix_sums_agree_with_serial_scan(
+        #[strategy(prop::collection::vec(arb(), 0..3 * BFieldElement::PARALLEL_CHUNK_SIZE))]
+        elements: Vec<BFieldElement>,
+    ) {
+        let serial_sums = serial_prefix_scan(&elements, BFIELD_ZERO, |a, b| a + b);
+        prop_assert_eq!(serial_sums, BFieldElement::prefix_sums(&elements));
+    }
+
+    #[proptest(cases = 20)]
+    fn prefix_products_agree_with_serial_scan(
+        #[strategy(prop::collection::vec(arb(), 0..3 * BFieldElement::PARALLEL_CHUNK_SIZE))]
+        elements: Vec<BFieldElement>,
+    ) {
+        let serial_products = serial_prefix_scan(&elements, BFIELD_ONE, |a, b| a * b);
+        prop_assert_eq!(serial_products, BFieldElement::prefix_products(&elements));
+    }
+
+    #[proptest]
+    fn zeroized_element_is_zero(mut element: BFieldElement) {
+        element.zeroize();
+        prop_assert_eq!(BFieldElement::zero(), element);
+        prop_assert_eq!(0, element.raw_u64());
+    }
+
+    #[test]
+    fn prefix_scans_of_small_example() {
+        let elements = [1, 2, 3, 4].map(BFieldElement::new);
+        let sums = [1, 3, 6, 10].map(BFieldElement::new);
+        let products = [1, 2, 6, 24].map(BFieldElement::new);
+        assert_eq!(sums.to_vec(), BFieldElement::prefix_sums(&elements));
+        assert_eq!(products.to_vec(), BFieldElement::prefix_products(&elements));
+        assert!(BFieldElement::prefix_sums(&[]).is_empty());
+        assert!(BFieldElement::prefix_products(&[]).is_empty());
+    }
+
+    #[proptest]
+    fn zero_powers_are_empty(base: BFieldElement) {
+        prop_assert!(BFieldElement::powers(base, 0).is_empty());
+        prop_assert!(BFieldElement::powers_parallel(base, 0).is_empty());
+    }
+
+    #[proptest]
+    fn parallel_reduction_agrees_with_serial_reduction(raw_values: Vec<u64>) {
+        let elements = raw_values
+            .into_iter()
+            .map(BFieldElement::from_raw_u64)
+            .collect::<Vec<_>>();
+        let serially_reduced = elements.iter().map(|e| e.reduced()).collect::<Vec<_>>();
+        let mut reduced = elements.clone();
+        BFieldElement::reduce_slice_parallel(&mut reduced);
+        prop_assert_eq!(&serially_reduced, &reduced);
+
+        for (element, reduced_element) in elements.iter().zip(&reduced) {
+            prop_assert!(reduced_element.raw_u64() < BFieldElement::P);
+            prop_assert_eq!(element.value(), reduced_element.value());
+        }
+    }
+
+    #[proptest]
+    fn reducing_results_of_arithmetic_changes_nothing(
+        #[strategy(prop::collection::vec(arb(), 0..100))] a: Vec<BFieldElement>,
+        #[strategy(prop::collection::vec(arb(), #a.len()))] b: Vec<BFieldElement>,
+    ) {
+        let products = BFieldElement::pointwise_mul(&a, &b);
+        let mut reduced_products = products.clone();
+        BFieldElement::reduce_slice_parallel(&mut reduced_products);
+        prop_assert_eq!(products, reduced_products);
+    }
+
     #[proptest]
     fn get_size(bfe: BFieldElement) {
         prop_assert_eq!(8, bfe.get_size());
@@ -663,6 +1125,36 @@ mod b_prime_field_element_test {
         prop_assert_eq!(bfe, deserialized);
     }
 
+    #[proptest]
+    fn serialization_to_json_gives_canonical_decimal_value(bfe: BFieldElement) {
+        let serialized = serde_json::to_string(&bfe).unwrap();
+        prop_assert_eq!(bfe.value().to_string(), serialized);
+    }
+
+    #[proptest]
+    fn serialization_and_deserialization_to_and_from_bincode_is_identity(bfe: BFieldElement) {
+        let serialized = bincode::serialize(&bfe).unwrap();
+        prop_assert_eq!(bfe.to_canonical_bytes().to_vec(), serialized.clone());
+        let deserialized: BFieldElement = bincode::deserialize(&serialized).unwrap();
+        prop_assert_eq!(bfe, deserialized);
+    }
+
+    #[proptest]
+    fn deserializing_non_canonical_values_fails(#[strategy(BFieldElement::P..)] value: u64) {
+        let json_result = serde_json::from_str::<BFieldElement>(&value.to_string());
+        prop_assert!(json_result.is_err());
+
+        let bincode_result = bincode::deserialize::<BFieldElement>(&value.to_le_bytes());
+        prop_assert!(bincode_result.is_err());
+    }
+
+    #[test]
+    fn deserializing_p_fails() {
+        let p = BFieldElement::P;
+        assert!(serde_json::from_str::<BFieldElement>(&p.to_string()).is_err());
+        assert!(bincode::deserialize::<BFieldElement>(&p.to_le_bytes()).is_err());
+    }
+
     #[proptest]
     fn deserializing_u64_is_like_calling_new(#[strategy(0..=BFieldElement::MAX)] value: u64) {
         let bfe = BFieldElement::new(value);
@@ -754,10 +1246,53 @@ mod b_prime_field_element_test {
         assert_eq!("7", format!("{seven}"));
 
         let minus_one: BFieldElement = BFieldElement::new(BFieldElement::P - 1);
-        assert_eq!("-1", format!("{minus_one}"));
+        assert_eq!("18446744069414584320", format!("{minus_one}"));
 
         let minus_fifteen: BFieldElement = BFieldElement::new(BFieldElement::P - 15);
-        assert_eq!("-15", format!("{minus_fifteen}"));
+        assert_eq!("18446744069414584306", format!("{minus_fifteen}"));
+    }
+
+    #[proptest]
+    fn display_and_from_str_round_trip(bfe: BFieldElement) {
+        prop_assert_eq!(Ok(bfe), BFieldElement::from_str(&bfe.to_string()));
+    }
+
+    #[proptest]
+    fn hex_and_decimal_strings_parse_to_same_element(#[strategy(0..BFieldElement::P)] value: u64) {
+        let from_decimal = BFieldElement::from_str(&format!("{value}"));
+        let from_hex = BFieldElement::from_str(&format!("{value:#x}"));
+        prop_assert_eq!(Ok(BFieldElement::new(value)), from_decimal);
+        prop_assert_eq!(from_decimal, from_hex);
+    }
+
+    #[test]
+    fn parsing_hex_string() {
+        let bfe = BFieldElement::from_str("0xff").unwrap();
+        assert_eq!(BFieldElement::new(255), bfe);
+
+        let max = BFieldElement::from_str("0xffffffff00000000").unwrap();
+        assert_eq!(BFieldElement::new(BFieldElement::MAX), max);
+    }
+
+    #[test]
+    fn parsing_non_canonical_strings_fails() {
+        let p = BFieldElement::P;
+        let err = BFieldElement::from_str(&p.to_string()).unwrap_err();
+        assert_eq!(ParseBFieldElementError::NotCanonical(p), err);
+
+        let err = BFieldElement::from_str(&format!("{p:#x}")).unwrap_err();
+        assert_eq!(ParseBFieldElementError::NotCanonical(p), err);
+
+        let err = BFieldElement::from_str(&u64::MAX.to_string()).unwrap_err();
+        assert_eq!(ParseBFieldElementError::NotCanonical(u64::MAX), err);
+    }
+
+    #[test]
+    fn parsing_malformed_strings_fails() {
+        for malformed in ["", "0x", "-1", "0xg", "1.0", "18446744073709551616"] {
+            let err = BFieldElement::from_str(malformed).unwrap_err();
+            assert!(matches!(err, ParseBFieldElementError::ParseU64Error(_)));
+        }
     }
 
     #[test]
@@ -890,6 +1425,36 @@ mod b_prime_field_element_test {
         }
     }
 
+    #[proptest]
+    fn batch_inverse_agrees_with_inverse_or_zero(bfes: Vec<BFieldElement>) {
+        let bfes_inv = BFieldElement::batch_inverse(&bfes);
+        prop_assert_eq!(bfes.len(), bfes_inv.len());
+        for (bfe, bfe_inv) in izip!(bfes, bfes_inv) {
+            prop_assert_eq!(bfe.inverse_or_zero(), bfe_inv);
+        }
+    }
+
+    #[test]
+    fn batch_inverse_maps_zeros_to_zero() {
+        let bfes = [0, 3, 0, 0, 7, 0].map(BFieldElement::new);
+        let bfes_inv = BFieldElement::batch_inverse(&bfes);
+        let expected = vec![
+            BFieldElement::zero(),
+            BFieldElement::new(3).inverse(),
+            BFieldElement::zero(),
+            BFieldElement::zero(),
+            BFieldElement::new(7).inverse(),
+            BFieldElement::zero(),
+        ];
+        assert_eq!(expected, bfes_inv);
+
+        assert!(BFieldElement::batch_inverse(&[]).is_empty());
+        assert_eq!(
+            vec![BFieldElement::zero(); 3],
+            BFieldElement::batch_inverse(&[BFieldElement::zero(); 3])
+        );
+    }
+
     #[test]
     fn power_accumulator_simple_test() {
         let input_a = [
@@ -1134,6 +1699,27 @@ mod b_prime_field_element_test {
         }
     }
 
+    #[proptest]
+    fn primitive_root_of_unity_does_not_exist_for_non_power_of_two_order(
+        #[filter(!#order.is_power_of_two() && #order > 1)] order: u64,
+    ) {
+        prop_assert!(BFieldElement::primitive_root_of_unity(order).is_none());
+    }
+
+    #[test]
+    fn primitive_root_of_unity_does_not_exist_for_order_beyond_two_adicity() {
+        for log2_order in 33..64 {
+            let order = 1 << log2_order;
+            assert!(BFieldElement::primitive_root_of_unity(order).is_none());
+        }
+    }
+
+    #[test]
+    fn primitive_root_of_unity_of_trivial_order_is_one() {
+        assert_eq!(Some(BFIELD_ONE), BFieldElement::primitive_root_of_unity(0));
+        assert_eq!(Some(BFIELD_ONE), BFieldElement::primitive_root_of_unity(1));
+    }
+
     #[test]
     #[should_panic(expected = "Attempted to find the multiplicative inverse of zero.")]
     fn multiplicative_inverse_of_zero() {
@@ -1215,6 +1801,98 @@ mod b_prime_field_element_test {
         assert_eq!(a.value(), b.value());
     }
 
+    #[proptest]
+    fn canonical_bytes_round_trip(bfe: BFieldElement) {
+        let bytes = bfe.to_canonical_bytes();
+        prop_assert_eq!(bfe.value().to_le_bytes(), bytes);
+        let decoded = BFieldElement::try_from_canonical_bytes(&bytes).unwrap();
+        prop_assert_eq!(bfe, decoded);
+    }
+
+    #[test]
+    fn non_canonical_bytes_are_rejected() {
+        for value in [BFieldElement::P, BFieldElement::P + 1, u64::MAX] {
+            let err = BFieldElement::try_from_canonical_bytes(&value.to_le_bytes()).unwrap_err();
+            assert_eq!(ParseBFieldElementError::NotCanonical(value), err);
+        }
+
+        let max = BFieldElement::try_from_canonical_bytes(&BFieldElement::MAX.to_le_bytes());
+        assert_eq!(BFieldElement::new(BFieldElement::MAX), max.unwrap());
+    }
+
+    #[proptest]
+    fn pointwise_mul_agrees_with_scalar_mul(
+        #[strategy(0_usize..100)] _len: usize,
+        #[strategy(prop::collection::vec(arb(), #_len))] a: Vec<BFieldElement>,
+        #[strategy(prop::collection::vec(arb(), #_len))] b: Vec<BFieldElement>,
+    ) {
+        let expected = a.iter().zip(&b).map(|(&x, &y)| x * y).collect::<Vec<_>>();
+        prop_assert_eq!(expected, BFieldElement::pointwise_mul(&a, &b));
+    }
+
+    #[test]
+    #[should_panic(expected = "equal length")]
+    fn pointwise_mul_panics_on_length_mismatch() {
+        let a = random_elements(3);
+        let b = random_elements(4);
+        BFieldElement::pointwise_mul(&a, &b);
+    }
+
+    #[test]
+    fn try_new_accepts_canonical_and_rejects_non_canonical_values() {
+        let max = BFieldElement::try_new(BFieldElement::P - 1).unwrap();
+        assert_eq!(BFieldElement::new(BFieldElement::P - 1), max);
+
+        for value in [BFieldElement::P, BFieldElement::P + 1, u64::MAX] {
+            let err = BFieldElement::try_new(value).unwrap_err();
+            assert_eq!(ParseBFieldElementError::NotCanonical(value), err);
+        }
+    }
+
+    #[proptest]
+    fn try_new_agrees_with_new_on_canonical_values(#[strategy(0..BFieldElement::P)] value: u64) {
+        prop_assert_eq!(
+            BFieldElement::new(value),
+            BFieldElement::try_new(value).unwrap()
+        );
+    }
+
+    #[proptest]
+    fn sqrt_of_square_squares_to_original(bfe: BFieldElement) {
+        let square = bfe.square();
+        prop_assert!(square.legendre_symbol() >= 0);
+        let root = square.sqrt().unwrap();
+        prop_assert_eq!(square, root.square());
+        prop_assert!(root == bfe || root == -bfe);
+    }
+
+    #[proptest]
+    fn sqrt_exists_iff_legendre_symbol_is_not_minus_one(bfe: BFieldElement) {
+        let root = bfe.sqrt();
+        prop_assert_eq!(bfe.legendre_symbol() == -1, root.is_none());
+        if let Some(root) = root {
+            prop_assert_eq!(bfe, root.square());
+        }
+    }
+
+    #[test]
+    fn sqrt_edge_cases() {
+        assert_eq!(0, BFieldElement::zero().legendre_symbol());
+        assert_eq!(Some(BFieldElement::zero()), BFieldElement::zero().sqrt());
+
+        for small_root in 1..=100 {
+            let small_root = BFieldElement::new(small_root);
+            let square = small_root.square();
+            assert_eq!(1, square.legendre_symbol());
+            let root = square.sqrt().unwrap();
+            assert!(root == small_root || root == -small_root);
+        }
+
+        let non_residue = BFieldElement::generator();
+        assert_eq!(-1, non_residue.legendre_symbol());
+        assert!(non_residue.sqrt().is_none());
+    }
+
     #[test]
     fn test_random_raw() {
         let mut rng = thread_rng();
@@ -373,6 +373,40 @@ impl<T: BFieldCodec> BFieldCodec for Vec<T> {
     }
 }
 
+/// Decode a `Vec<T>` from a sequence that holds exactly its encoding, including the length
+/// indicator. Used by the [`BFieldCodec`] derive macro for fields of type `Vec<T>`, the
+/// encoding length of which is already known.
+///
+/// Agrees with [`Vec::<T>::decode`](BFieldCodec::decode) on all inputs. If `T` has a non-zero
+/// [static length](BFieldCodec::static_length), however, the number of elements follows from
+/// the length of the sequence, and the length indicator is only checked against it.
+pub fn decode_vec_field<T: BFieldCodec>(
+    sequence: &[BFieldElement],
+) -> Result<Vec<T>, BFieldCodecError> {
+    let item_length = match T::static_length() {
+        Some(item_length) if item_length > 0 => item_length,
+        _ => return Vec::decode(sequence).map(|vec| *vec),
+    };
+    let Some((indicated_num_items, raw_items)) = sequence.split_first() else {
+        return Err(BFieldCodecError::EmptySequence);
+    };
+
+    let num_items = raw_items.len() / item_length;
+    let is_consistent =
+        raw_items.len() % item_length == 0 && indicated_num_items.value() == num_items as u64;
+    if !is_consistent {
+        // the general path reports the precise error
+        return Vec::decode(sequence).map(|vec| *vec);
+    }
+
+    let mut vec = Vec::with_capacity(num_items);
+    for raw_item in raw_items.chunks_exact(item_length) {
+        let item = *T::decode(raw_item).map_err(|e| e.into())?;
+        vec.push(item);
+    }
+    Ok(vec)
+}
+
 /// The core of the [`BFieldCodec`] decoding logic for `Vec<T>` and `[T; N]`.
 /// Decoding the length-prepending must be handled by the caller (if necessary).
 fn bfield_codec_decode_list<T: BFieldCodec>(
@@ -1634,5 +1668,90 @@ mod tests {
         ) {
             test_data.assert_bfield_codec_properties()?;
         }
+
+        #[derive(Debug, Clone, PartialEq, Eq, BFieldCodec)]
+        struct WithLengthCappedField {
+            a: u64,
+            #[bfield_codec(max_length = 10)]
+            b: Vec<Vec<u64>>,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq, BFieldCodec)]
+        struct TupleWithLengthCappedField(u64, #[bfield_codec(max_length = 10)] Vec<u64>);
+
+        #[derive(Debug, Clone, PartialEq, Eq, BFieldCodec)]
+        enum EnumWithLengthCappedField {
+            A(#[bfield_codec(max_length = 10)] Vec<u64>),
+        }
+
+        #[test]
+        fn length_capped_field_within_bounds_can_be_decoded() {
+            let with_capped_field = WithLengthCappedField {
+                a: 42,
+                b: vec![vec![1, 2], vec![]],
+            };
+            let encoding = with_capped_field.encode();
+            let decoded = *WithLengthCappedField::decode(&encoding).unwrap();
+            assert_eq!(with_capped_field, decoded);
+
+            let tuple_with_capped_field = TupleWithLengthCappedField(42, vec![1, 2, 3, 4]);
+            let encoding = tuple_with_capped_field.encode();
+            let decoded = *TupleWithLengthCappedField::decode(&encoding).unwrap();
+            assert_eq!(tuple_with_capped_field, decoded);
+
+            let enum_with_capped_field = EnumWithLengthCappedField::A(vec![1, 2, 3, 4]);
+            let encoding = enum_with_capped_field.encode();
+            let decoded = *EnumWithLengthCappedField::decode(&encoding).unwrap();
+            assert_eq!(enum_with_capped_field, decoded);
+        }
+
+        #[test]
+        fn length_capped_field_exceeding_bounds_cannot_be_decoded() {
+            let with_capped_field = WithLengthCappedField {
+                a: 42,
+                b: vec![vec![1, 2, 3, 4]],
+            };
+            let encoding = with_capped_field.encode();
+            let err = WithLengthCappedField::decode(&encoding).unwrap_err();
+            assert!(matches!(
+                err,
+                WithLengthCappedFieldBFieldDecodingError::LengthExceedsMaximumForField(_, 11)
+            ));
+
+            let tuple_with_capped_field = TupleWithLengthCappedField(42, vec![1, 2, 3, 4, 5]);
+            let encoding = tuple_with_capped_field.encode();
+            let err = TupleWithLengthCappedField::decode(&encoding).unwrap_err();
+            assert!(matches!(
+                err,
+                TupleWithLengthCappedFieldBFieldDecodingError::LengthExceedsMaximumForField(_, 11)
+            ));
+
+            let enum_with_capped_field = EnumWithLengthCappedField::A(vec![1, 2, 3, 4, 5]);
+            let encoding = enum_with_capped_field.encode();
+            let err = EnumWithLengthCappedField::decode(&encoding).unwrap_err();
+            assert!(matches!(
+                err,
+                EnumWithLengthCappedFieldBFieldDecodingError::LengthExceedsMaximumForVariant(
+                    0, 0, 11
+                )
+            ));
+        }
+
+        #[test]
+        fn enormous_length_indicator_of_capped_field_is_rejected() {
+            let enormous_length = BFieldElement::MAX;
+            let inner_length = BFieldElement::MAX;
+            let sequence = [42, 0, enormous_length, 1, inner_length].map(BFieldElement::new);
+            let err = WithLengthCappedField::decode(&sequence).unwrap_err();
+            let WithLengthCappedFieldBFieldDecodingError::LengthExceedsMaximumForField(
+                field_name,
+                length,
+            ) = err
+            else {
+                panic!("expected the length indicator to be rejected");
+            };
+            assert_eq!("b", field_name);
+            assert_eq!(enormous_length as usize, length);
+        }
     }
 }
@@ -2,6 +2,9 @@ use core::fmt;
 use std::str::FromStr;
 
 use arbitrary::Arbitrary;
+use bech32::FromBase32;
+use bech32::ToBase32;
+use bech32::Variant;
 use bfieldcodec_derive::BFieldCodec;
 use get_size::GetSize;
 use itertools::Itertools;
@@ -12,10 +15,14 @@ use rand_distr::Distribution;
 use rand_distr::Standard;
 use serde::Deserialize;
 use serde::Serialize;
+use subtle::Choice;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
 
 use crate::error::TryFromDigestError;
 use crate::shared_math::b_field_element::BFieldElement;
 use crate::shared_math::b_field_element::BFIELD_ZERO;
+use crate::shared_math::tip5::Tip5;
 use crate::shared_math::traits::FromVecu8;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
 use crate::util_types::emojihash_trait::Emojihash;
@@ -43,6 +50,10 @@ impl PartialOrd for Digest {
     }
 }
 
+/// Digests are ordered by the canonical values of their elements, most significant element,
+/// _i.e._, the last one, first. This agrees with the ordering of the corresponding
+/// [`BigUint`]s as well as with the ordering of the [hex encodings](Digest::to_hex), and is
+/// guaranteed to remain stable across releases.
 impl Ord for Digest {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         let Digest(self_inner) = self;
@@ -56,6 +67,10 @@ impl Ord for Digest {
 impl Digest {
     pub const BYTES: usize = DIGEST_LENGTH * BFieldElement::BYTES;
 
+    /// Any 7 bytes fit into one canonical [`BFieldElement`], and 5 elements suffice for the 32
+    /// bytes of a [`blake3::Hash`].
+    const BLAKE3_BYTES_PER_ELEMENT: usize = 7;
+
     pub fn values(self) -> [BFieldElement; DIGEST_LENGTH] {
         self.0
     }
@@ -69,6 +84,142 @@ impl Digest {
     pub const fn reversed(self) -> Digest {
         Digest([self.0[4], self.0[3], self.0[2], self.0[1], self.0[0]])
     }
+
+    /// Hex encoding of the digest: the canonical values of all elements as big-endian bytes,
+    /// most significant element, _i.e._, the last one, first. In other words, the zero-padded
+    /// hex encoding of the corresponding [`BigUint`]. Hence, comparing the hex encodings of two
+    /// digests agrees with the digests' [ordering](Ord).
+    pub fn to_hex(&self) -> String {
+        self.0
+            .iter()
+            .flat_map(|bfe| bfe.to_canonical_bytes())
+            .rev()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Inverse of [`to_hex`](Self::to_hex). Accepts upper- and lowercase hex digits, and
+    /// rejects encodings of non-canonical elements.
+    pub fn try_from_hex(hex: &str) -> Result<Self, TryFromDigestError> {
+        if hex.len() != 2 * Self::BYTES {
+            return Err(TryFromDigestError::InvalidHexLength(hex.len()));
+        }
+
+        let nibbles = hex
+            .chars()
+            .map(|c| {
+                c.to_digit(16)
+                    .map(|nibble| nibble as u8)
+                    .ok_or(TryFromDigestError::InvalidHexCharacter(c))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let bytes = nibbles
+            .chunks(2)
+            .rev()
+            .map(|pair| (pair[0] << 4) | pair[1])
+            .collect_vec();
+        Self::try_from_canonical_bytes(&bytes)
+    }
+
+    /// [Bech32m](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki) encoding of
+    /// the digest's canonical bytes under the given human-readable part. The checksum catches
+    /// typos, making the encoding suitable for showing digests to users.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the human-readable part is invalid, _e.g._, if it is empty or contains
+    /// non-ASCII characters.
+    pub fn to_bech32m(&self, hrp: &str) -> String {
+        let bytes = self.0.iter().flat_map(|bfe| bfe.to_canonical_bytes());
+        let data = bytes.collect_vec().to_base32();
+        bech32::encode(hrp, data, Variant::Bech32m).expect("human-readable part must be valid")
+    }
+
+    /// Inverse of [`to_bech32m`](Self::to_bech32m). Returns the human-readable part alongside
+    /// the digest. Rejects encodings with an invalid checksum, of the wrong length, or of
+    /// non-canonical elements.
+    pub fn from_bech32m(encoding: &str) -> Result<(String, Self), TryFromDigestError> {
+        let (hrp, data, variant) = bech32::decode(encoding)?;
+        if variant != Variant::Bech32m {
+            return Err(TryFromDigestError::InvalidBech32Variant);
+        }
+
+        let bytes = Vec::<u8>::from_base32(&data)?;
+        if bytes.len() != Self::BYTES {
+            return Err(TryFromDigestError::InvalidByteLength(bytes.len()));
+        }
+        let digest = Self::try_from_canonical_bytes(&bytes)?;
+        Ok((hrp, digest))
+    }
+
+    /// The byte slice must have length [`Digest::BYTES`].
+    fn try_from_canonical_bytes(bytes: &[u8]) -> Result<Self, TryFromDigestError> {
+        debug_assert_eq!(Self::BYTES, bytes.len());
+        let elements = bytes
+            .chunks(BFieldElement::BYTES)
+            .map(|chunk| BFieldElement::try_from_canonical_bytes(chunk.try_into().unwrap()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Digest(elements.try_into().unwrap()))
+    }
+
+    /// Pack a [`blake3::Hash`] into a digest, for example when migrating commitments from blake3
+    /// to [`Tip5`]. The 32 bytes are split into chunks of 7, 7, 7, 7, and 4 bytes. Every chunk,
+    /// interpreted as a little-endian integer, is smaller than the field's prime and thus becomes
+    /// one canonical [`BFieldElement`]. Hence, the packing is injective for all hashes.
+    ///
+    /// Note that this packing differs from the one used internally by the
+    /// [`AlgebraicHasher`] implementation for [`blake3::Hasher`].
+    pub fn from_blake3(hash: blake3::Hash) -> Self {
+        let elements = hash
+            .as_bytes()
+            .chunks(Self::BLAKE3_BYTES_PER_ELEMENT)
+            .map(|chunk| {
+                let mut bytes = [0; BFieldElement::BYTES];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                BFieldElement::new(u64::from_le_bytes(bytes))
+            })
+            .collect_vec();
+        Digest(elements.try_into().unwrap())
+    }
+
+    /// The inverse of [`from_blake3`](Self::from_blake3). Returns `None` if the digest is not
+    /// the result of packing some [`blake3::Hash`], _i.e._, if any element is too large for its
+    /// chunk of bytes. This is the case for almost all digests not produced by
+    /// [`from_blake3`](Self::from_blake3).
+    pub fn to_blake3(&self) -> Option<blake3::Hash> {
+        let mut bytes = Vec::with_capacity(blake3::OUT_LEN);
+        for element in self.0 {
+            let num_bytes = (blake3::OUT_LEN - bytes.len()).min(Self::BLAKE3_BYTES_PER_ELEMENT);
+            let value = element.value();
+            if value >> (8 * num_bytes) != 0 {
+                return None;
+            }
+            bytes.extend_from_slice(&value.to_le_bytes()[..num_bytes]);
+        }
+        let bytes: [u8; blake3::OUT_LEN] = bytes.try_into().unwrap();
+        Some(blake3::Hash::from(bytes))
+    }
+
+    /// Deterministically derive a digest from a seed by hashing it with [`Tip5`]. Intended for
+    /// constructing reproducible digests in tests, including those of dependent crates.
+    pub fn from_seed(seed: u64) -> Self {
+        let limbs = [seed & 0xffff_ffff, seed >> 32].map(BFieldElement::new);
+        Tip5::hash_varlen(&limbs)
+    }
+}
+
+/// Compares all elements, independent of where the first difference is. Prefer this over `==`
+/// when comparing against secret values.
+impl ConstantTimeEq for Digest {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(Choice::from(1), |equal_so_far, (a, b)| {
+                equal_so_far & a.raw_u64().ct_eq(&b.raw_u64())
+            })
+    }
 }
 
 impl Emojihash for Digest {
@@ -77,6 +228,15 @@ impl Emojihash for Digest {
     }
 }
 
+/// Overwrites all elements with zero, clearing the internal storage. See
+/// [`BFieldElement`'s implementation](BFieldElement#impl-Zeroize-for-BFieldElement) for wiping
+/// secrets on drop.
+impl Zeroize for Digest {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl Default for Digest {
     fn default() -> Self {
         Self([BFIELD_ZERO; DIGEST_LENGTH])
@@ -219,6 +379,8 @@ pub(crate) mod digest_tests {
     use proptest_arbitrary_interop::arb;
     use test_strategy::proptest;
 
+    use crate::error::ParseBFieldElementError;
+
     use super::*;
 
     impl ProptestArbitrary for Digest {
@@ -287,6 +449,236 @@ pub(crate) mod digest_tests {
         assert!(second_invalid_digest.is_err());
     }
 
+    #[proptest]
+    fn hex_encoding_round_trips(digest: Digest) {
+        let hex = digest.to_hex();
+        prop_assert_eq!(2 * Digest::BYTES, hex.len());
+        prop_assert_eq!(digest, Digest::try_from_hex(&hex).unwrap());
+        prop_assert_eq!(digest, Digest::try_from_hex(&hex.to_uppercase()).unwrap());
+    }
+
+    #[test]
+    fn hex_encoding_of_known_digest_is_stable() {
+        let digest = Digest::new([1, 2, 0xff, BFieldElement::MAX, 0].map(BFieldElement::new));
+        let expected = "0000000000000000\
+            ffffffff00000000\
+            00000000000000ff\
+            0000000000000002\
+            0000000000000001";
+        assert_eq!(expected, digest.to_hex());
+        assert_eq!(digest, Digest::try_from_hex(expected).unwrap());
+    }
+
+    #[test]
+    fn invalid_hex_encodings_are_rejected() {
+        let valid_hex = Digest::default().to_hex();
+
+        let too_short = &valid_hex[1..];
+        let err = Digest::try_from_hex(too_short).unwrap_err();
+        assert_eq!(TryFromDigestError::InvalidHexLength(79), err);
+
+        let too_long = format!("{valid_hex}00");
+        let err = Digest::try_from_hex(&too_long).unwrap_err();
+        assert_eq!(TryFromDigestError::InvalidHexLength(82), err);
+
+        let not_hex = format!("g{}", &valid_hex[1..]);
+        let err = Digest::try_from_hex(&not_hex).unwrap_err();
+        assert_eq!(TryFromDigestError::InvalidHexCharacter('g'), err);
+
+        let p_as_hex = "0000000000000000\
+            0000000000000000\
+            ffffffff00000001\
+            0000000000000001\
+            0000000000000001";
+        let err = Digest::try_from_hex(p_as_hex).unwrap_err();
+        let not_canonical = ParseBFieldElementError::NotCanonical(BFieldElement::P);
+        assert_eq!(TryFromDigestError::InvalidBFieldElement(not_canonical), err);
+    }
+
+    #[proptest]
+    fn converting_digest_to_vec_and_back_is_identity(digest: Digest) {
+        let elements: Vec<BFieldElement> = digest.into();
+        prop_assert_eq!(DIGEST_LENGTH, elements.len());
+        prop_assert_eq!(digest, Digest::try_from(elements.as_slice()).unwrap());
+        prop_assert_eq!(digest, Digest::try_from(elements).unwrap());
+    }
+
+    #[proptest]
+    fn converting_vec_of_wrong_length_to_digest_fails(
+        #[strategy(vec(arb(), 0..20))]
+        #[filter(#elements.len() != DIGEST_LENGTH)]
+        elements: Vec<BFieldElement>,
+    ) {
+        let num_elements = elements.len();
+        let slice_err = Digest::try_from(elements.as_slice()).unwrap_err();
+        prop_assert_eq!(TryFromDigestError::InvalidLength(num_elements), slice_err);
+
+        let vec_err = Digest::try_from(elements).unwrap_err();
+        prop_assert_eq!(TryFromDigestError::InvalidLength(num_elements), vec_err);
+    }
+
+    #[test]
+    fn converting_vec_of_wrong_length_to_digest_gives_descriptive_error() {
+        let too_short = vec![BFieldElement::one(); DIGEST_LENGTH - 1];
+        let err = Digest::try_from(too_short).unwrap_err();
+        assert_eq!("expected 5 elements for digest, but got 4", err.to_string());
+
+        let too_long = vec![BFieldElement::one(); DIGEST_LENGTH + 1];
+        let err = Digest::try_from(too_long).unwrap_err();
+        assert_eq!("expected 5 elements for digest, but got 6", err.to_string());
+    }
+
+    #[proptest]
+    fn zeroized_digest_is_all_zeros(mut digest: Digest) {
+        digest.zeroize();
+        prop_assert_eq!(Digest::default(), digest);
+        prop_assert!(digest.0.iter().all(|element| element.raw_u64() == 0));
+    }
+
+    #[proptest]
+    fn blake3_packing_round_trips(bytes: [u8; blake3::OUT_LEN]) {
+        let hash = blake3::Hash::from(bytes);
+        let digest = Digest::from_blake3(hash);
+        prop_assert_eq!(Some(hash), digest.to_blake3());
+    }
+
+    #[proptest]
+    fn blake3_packing_is_injective(
+        bytes: [u8; blake3::OUT_LEN],
+        #[filter(#bytes != #other_bytes)] other_bytes: [u8; blake3::OUT_LEN],
+    ) {
+        let digest = Digest::from_blake3(blake3::Hash::from(bytes));
+        let other_digest = Digest::from_blake3(blake3::Hash::from(other_bytes));
+        prop_assert_ne!(digest, other_digest);
+    }
+
+    #[test]
+    fn blake3_packing_of_extreme_hashes() {
+        let all_zeros = Digest::from_blake3(blake3::Hash::from([0; blake3::OUT_LEN]));
+        assert_eq!(Digest::default(), all_zeros);
+
+        let all_ones = Digest::from_blake3(blake3::Hash::from([0xff; blake3::OUT_LEN]));
+        let expected = [
+            (1 << 56) - 1,
+            (1 << 56) - 1,
+            (1 << 56) - 1,
+            (1 << 56) - 1,
+            u32::MAX.into(),
+        ];
+        assert_eq!(Digest::new(expected.map(BFieldElement::new)), all_ones);
+    }
+
+    #[test]
+    fn digests_with_too_large_elements_are_not_blake3_hashes() {
+        let too_large_element = Digest::new([0, 0, 0, 1 << 56, 0].map(BFieldElement::new));
+        assert_eq!(None, too_large_element.to_blake3());
+
+        let too_large_last_element = Digest::new([0, 0, 0, 0, 1 << 32].map(BFieldElement::new));
+        assert_eq!(None, too_large_last_element.to_blake3());
+    }
+
+    #[proptest]
+    fn bech32m_encoding_round_trips(digest: Digest, #[strategy("[a-z]{1,10}")] hrp: String) {
+        let encoding = digest.to_bech32m(&hrp);
+        let (decoded_hrp, decoded_digest) = Digest::from_bech32m(&encoding).unwrap();
+        prop_assert_eq!(hrp, decoded_hrp);
+        prop_assert_eq!(digest, decoded_digest);
+    }
+
+    #[proptest]
+    fn bech32m_encoding_with_one_corrupt_character_is_rejected(
+        digest: Digest,
+        #[strategy(0_usize..70)] corrupt_index: usize,
+        #[strategy(1_usize..32)] offset: usize,
+    ) {
+        const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+        let hrp = "digest";
+        let encoding = digest.to_bech32m(hrp);
+        let data_part = &encoding[hrp.len() + 1..];
+        let corrupt_index = corrupt_index % data_part.len();
+
+        let mut corrupt_data_part = data_part.as_bytes().to_vec();
+        let char_index = CHARSET
+            .iter()
+            .position(|&c| c == corrupt_data_part[corrupt_index])
+            .unwrap();
+        corrupt_data_part[corrupt_index] = CHARSET[(char_index + offset) % CHARSET.len()];
+        let corrupt_data_part = String::from_utf8(corrupt_data_part).unwrap();
+        let corrupt_encoding = format!("{hrp}1{corrupt_data_part}");
+
+        prop_assert!(Digest::from_bech32m(&corrupt_encoding).is_err());
+    }
+
+    #[test]
+    fn bech32m_encoding_of_wrong_length_is_rejected() {
+        let too_short = bech32::encode("digest", [0_u8; 39].to_base32(), Variant::Bech32m).unwrap();
+        let err = Digest::from_bech32m(&too_short).unwrap_err();
+        assert_eq!(TryFromDigestError::InvalidByteLength(39), err);
+
+        let too_long = bech32::encode("digest", [0_u8; 41].to_base32(), Variant::Bech32m).unwrap();
+        let err = Digest::from_bech32m(&too_long).unwrap_err();
+        assert_eq!(TryFromDigestError::InvalidByteLength(41), err);
+    }
+
+    #[test]
+    fn bech32_encoding_is_rejected() {
+        let bytes = [0_u8; Digest::BYTES];
+        let encoding = bech32::encode("digest", bytes.to_base32(), Variant::Bech32).unwrap();
+        let err = Digest::from_bech32m(&encoding).unwrap_err();
+        assert_eq!(TryFromDigestError::InvalidBech32Variant, err);
+    }
+
+    #[proptest]
+    fn digest_ordering_agrees_with_biguint_ordering(a: Digest, b: Digest) {
+        let a_biguint: BigUint = a.into();
+        let b_biguint: BigUint = b.into();
+        prop_assert_eq!(a_biguint.cmp(&b_biguint), a.cmp(&b));
+    }
+
+    #[proptest]
+    fn sorting_digests_is_deterministic(mut digests: Vec<Digest>) {
+        let mut reversed_digests = digests.iter().rev().copied().collect_vec();
+        digests.sort();
+        reversed_digests.sort();
+        prop_assert_eq!(&digests, &reversed_digests);
+        prop_assert!(digests.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[proptest]
+    fn digest_ordering_agrees_with_hex_ordering(a: Digest, b: Digest) {
+        prop_assert_eq!(a.to_hex().cmp(&b.to_hex()), a.cmp(&b));
+    }
+
+    #[test]
+    fn sorting_digests_sorts_their_hex_encodings() {
+        let digests = (0..100).map(Digest::from_seed).sorted().collect_vec();
+        let hex_encodings = digests.iter().map(Digest::to_hex).collect_vec();
+        assert!(hex_encodings.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn digest_ordering_compares_most_significant_element_first() {
+        let small = Digest::new([BFieldElement::MAX, 0, 0, 0, 0].map(BFieldElement::new));
+        let large = Digest::new([0, 0, 0, 0, 1].map(BFieldElement::new));
+        assert!(small < large);
+
+        let lifted = |x| Digest::new([x, 0, 0, 0, 0].map(BFieldElement::new));
+        assert!(lifted(1) < lifted(2));
+    }
+
+    #[proptest]
+    fn constant_time_equality_agrees_with_equality(a: Digest, b: Digest) {
+        prop_assert!(bool::from(a.ct_eq(&a)));
+        prop_assert_eq!(a == b, bool::from(a.ct_eq(&b)));
+    }
+
+    #[proptest]
+    fn constant_time_equality_detects_any_corruption(digest: Digest, corruptor: DigestCorruptor) {
+        let corrupt_digest = corruptor.corrupt_digest(digest)?;
+        prop_assert!(!bool::from(digest.ct_eq(&corrupt_digest)));
+    }
+
     #[proptest]
     fn test_reversed_involution(digest: Digest) {
         prop_assert_eq!(digest, digest.reversed().reversed())
@@ -400,4 +792,17 @@ pub(crate) mod digest_tests {
 
         assert_eq!(TryFromDigestError::Overflow, err);
     }
+
+    #[proptest]
+    fn digest_from_seed_is_deterministic(seed: u64) {
+        prop_assert_eq!(Digest::from_seed(seed), Digest::from_seed(seed));
+    }
+
+    #[proptest]
+    fn digests_from_different_seeds_differ(
+        seed: u64,
+        #[filter(#seed != #other_seed)] other_seed: u64,
+    ) {
+        prop_assert_ne!(Digest::from_seed(seed), Digest::from_seed(other_seed));
+    }
 }
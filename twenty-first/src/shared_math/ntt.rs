@@ -2,14 +2,20 @@ use std::ops::MulAssign;
 
 use num_traits::Zero;
 use rand_distr::num_traits::One;
+use rayon::prelude::*;
 
-use crate::shared_math::traits::{FiniteField, ModPowU32};
+use crate::shared_math::traits::{FiniteField, ModPowU32, PrimitiveRootOfUnity};
 
 use super::{
     b_field_element::BFieldElement,
+    polynomial::Polynomial,
     traits::{Inverse, New},
+    x_field_element::XFieldElement,
 };
 
+/// Transforms smaller than this are performed serially by [`ntt_parallel`].
+const NTT_PARALLELIZATION_CUTOFF: usize = 1 << 14;
+
 /// ## Perform NTT on slices of prime-field elements
 ///
 /// NTTs are Number Theoretic Transforms, which are Discrete Fourier Transforms
@@ -96,6 +102,225 @@ pub fn ntt<FF: FiniteField + MulAssign<BFieldElement>>(
     }
 }
 
+/// ## Perform NTT on slices of prime-field elements, in parallel
+///
+/// Like [`ntt`], and with bit-for-bit identical output, but the independent
+/// butterflies within each stage are computed in parallel. Falls back to the
+/// serial [`ntt`] for small inputs, where parallelization does not pay off.
+///
+/// This transform is performed in-place.
+pub fn ntt_parallel<FF: FiniteField + MulAssign<BFieldElement>>(
+    x: &mut [FF],
+    omega: BFieldElement,
+    log_2_of_n: u32,
+) {
+    let n = x.len();
+    if n < NTT_PARALLELIZATION_CUTOFF {
+        ntt::<FF>(x, omega, log_2_of_n);
+        return;
+    }
+
+    // `n` must be a power of 2
+    debug_assert_eq!(n, 1 << log_2_of_n, "2^log2(n) == n must evaluate to true");
+
+    // `omega` must be a primitive root of unity of order `n`
+    debug_assert!(
+        omega.mod_pow(n as u64).is_one(),
+        "Got {omega} which is not a {n}th root of 1"
+    );
+    debug_assert!(!omega.mod_pow(n as u64 / 2).is_one());
+
+    for k in 0..n {
+        let rk = bitreverse_usize(k, log_2_of_n as usize);
+        if k < rk {
+            x.swap(rk, k);
+        }
+    }
+
+    let mut m = 1;
+    for _ in 0..log_2_of_n {
+        let w_m = omega.mod_pow((n / (2 * m)) as u64);
+        let twiddles = (0..m)
+            .scan(BFieldElement::one(), |w, _| {
+                let current = *w;
+                *w *= w_m;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+
+        let butterfly = |(u, v): (&mut FF, &mut FF), &w: &BFieldElement| {
+            let mut v_w = *v;
+            v_w *= w;
+            *v = *u - v_w;
+            *u += v_w;
+        };
+
+        x.par_chunks_mut(2 * m).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(m);
+            if m < NTT_PARALLELIZATION_CUTOFF {
+                lo.iter_mut()
+                    .zip(hi.iter_mut())
+                    .zip(twiddles.iter())
+                    .for_each(|(pair, w)| butterfly(pair, w));
+            } else {
+                lo.par_iter_mut()
+                    .zip(hi.par_iter_mut())
+                    .zip(twiddles.par_iter())
+                    .for_each(|(pair, w)| butterfly(pair, w));
+            }
+        });
+
+        m *= 2;
+    }
+}
+
+/// ## Perform NTT on many equally long slices of prime-field elements
+///
+/// Equivalent to calling [`ntt`] on every column, but the twiddle factors are
+/// computed only once and shared between all columns, which are transformed
+/// in parallel.
+///
+/// All columns must have length `n` = 2^`log_2_of_n`.
+///
+/// This transform is performed in-place.
+pub fn ntt_batch<FF: FiniteField + MulAssign<BFieldElement>>(
+    columns: &mut [Vec<FF>],
+    omega: BFieldElement,
+    log_2_of_n: u32,
+) {
+    let n = 1_usize << log_2_of_n;
+    debug_assert!(
+        columns.iter().all(|column| column.len() == n),
+        "all columns must have length 2^{log_2_of_n}"
+    );
+
+    let plan = NttPlan::with_omega(omega, log_2_of_n);
+    columns
+        .par_iter_mut()
+        .for_each(|column| plan.forward(column));
+}
+
+/// ## A reusable plan for NTTs of a fixed size
+///
+/// Holds the twiddle factors for transforms of length `n` = 2^`log_2_of_n`,
+/// such that repeated transforms of the same size don't recompute them.
+/// Results are identical to those of [`ntt`] and [`intt`].
+///
+/// ```
+/// # use twenty_first::shared_math::b_field_element::BFieldElement;
+/// # use twenty_first::shared_math::ntt::NttPlan;
+/// let plan = NttPlan::new(3);
+/// let original = (0..8).map(BFieldElement::new).collect::<Vec<_>>();
+/// let mut values = original.clone();
+/// plan.forward(&mut values);
+/// plan.inverse(&mut values);
+/// assert_eq!(original, values);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttPlan {
+    omega: BFieldElement,
+    log_2_of_n: u32,
+
+    /// `omega^0, …, omega^(n/2 - 1)`
+    twiddles: Vec<BFieldElement>,
+}
+
+impl NttPlan {
+    /// A plan for transforms of length 2^`log_2_of_n` over the canonical domain,
+    /// _i.e._, with `omega` the primitive root of unity of that order.
+    ///
+    /// Panics if `log_2_of_n` is larger than 32, as the base field has no roots of unity of
+    /// order larger than 2^32.
+    pub fn new(log_2_of_n: u32) -> Self {
+        assert!(
+            log_2_of_n <= 32,
+            "NTT length must be at most 2^32, but got 2^{log_2_of_n}"
+        );
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n)
+            .expect("primitive roots of unity of order at most 2^32 should exist");
+        Self::with_omega(omega, log_2_of_n)
+    }
+
+    fn with_omega(omega: BFieldElement, log_2_of_n: u32) -> Self {
+        let n = 1_usize << log_2_of_n;
+
+        // `omega` must be a primitive root of unity of order `n`
+        debug_assert!(
+            omega.mod_pow(n as u64).is_one(),
+            "Got {omega} which is not a {n}th root of 1"
+        );
+        debug_assert!(!omega.mod_pow(n as u64 / 2).is_one() || n == 1);
+
+        let twiddles = (0..n / 2)
+            .scan(BFieldElement::one(), |power, _| {
+                let current = *power;
+                *power *= omega;
+                Some(current)
+            })
+            .collect();
+
+        Self {
+            omega,
+            log_2_of_n,
+            twiddles,
+        }
+    }
+
+    pub fn omega(&self) -> BFieldElement {
+        self.omega
+    }
+
+    pub fn log_2_of_n(&self) -> u32 {
+        self.log_2_of_n
+    }
+
+    /// In-place forward transform, identical to [`ntt`] with this plan's parameters.
+    pub fn forward<FF: FiniteField + MulAssign<BFieldElement>>(&self, x: &mut [FF]) {
+        let n = x.len();
+        assert_eq!(1 << self.log_2_of_n, n, "plan and input lengths must match");
+
+        for k in 0..n {
+            let rk = bitreverse_usize(k, self.log_2_of_n as usize);
+            if k < rk {
+                x.swap(rk, k);
+            }
+        }
+
+        let mut m = 1;
+        for _ in 0..self.log_2_of_n {
+            let stride = n / (2 * m);
+            let mut k = 0;
+            while k < n {
+                for j in 0..m {
+                    let u = x[k + j];
+                    let mut v = x[k + j + m];
+                    v *= self.twiddles[j * stride];
+                    x[k + j] = u + v;
+                    x[k + j + m] = u - v;
+                }
+
+                k += 2 * m;
+            }
+
+            m *= 2;
+        }
+    }
+
+    /// In-place inverse transform, identical to [`intt`] with this plan's parameters.
+    ///
+    /// Transforming with `omega^-1` equals transforming with `omega` and
+    /// reversing all but the first element, so the twiddles are reused.
+    pub fn inverse<FF: FiniteField + MulAssign<BFieldElement>>(&self, x: &mut [FF]) {
+        self.forward(x);
+        x[1..].reverse();
+
+        let n_inv = BFieldElement::new(x.len() as u64).inverse();
+        for elem in x.iter_mut() {
+            *elem *= n_inv;
+        }
+    }
+}
+
 /// ## Perform INTT on slices of prime-field elements
 ///
 /// INTT is the inverse NTT, so abstractly,
@@ -123,6 +348,50 @@ pub fn intt<FF: FiniteField + MulAssign<BFieldElement>>(
     }
 }
 
+/// ## Perform NTT over a coset of the evaluation domain
+///
+/// Evaluates the polynomial with coefficients `x` on the coset
+/// *`offset`·⟨`omega`⟩*, _i.e._, on the points *`offset`·`omega`^i*, by scaling
+/// the `i`th coefficient with *`offset`^i* before performing a regular [`ntt`].
+///
+/// This transform is performed in-place.
+pub fn coset_ntt<FF: FiniteField + MulAssign<BFieldElement>>(
+    x: &mut [FF],
+    omega: BFieldElement,
+    log_2_of_n: u32,
+    offset: BFieldElement,
+) {
+    scale_by_powers(x, offset);
+    ntt::<FF>(x, omega, log_2_of_n);
+}
+
+/// ## Perform INTT over a coset of the evaluation domain
+///
+/// The inverse of [`coset_ntt`]: interpolates values on the coset
+/// *`offset`·⟨`omega`⟩* and divides *`offset`^i* back out of the `i`th
+/// coefficient.
+///
+/// This transform is performed in-place.
+pub fn coset_intt<FF: FiniteField + MulAssign<BFieldElement>>(
+    x: &mut [FF],
+    omega: BFieldElement,
+    log_2_of_n: u32,
+    offset: BFieldElement,
+) {
+    intt::<FF>(x, omega, log_2_of_n);
+    scale_by_powers(x, offset.inverse());
+}
+
+/// Multiply the `i`th element of `x` by `factor`^i.
+fn scale_by_powers<FF: MulAssign<BFieldElement>>(x: &mut [FF], factor: BFieldElement) {
+    let mut power = BFieldElement::one();
+    for elem in x.iter_mut() {
+        *elem *= power;
+        power *= factor;
+    }
+}
+
+/// Reverse the lowest `l` bits of `n`. See also [`bit_reverse_index`].
 #[inline]
 pub fn bitreverse_usize(mut n: usize, l: usize) -> usize {
     let mut r = 0;
@@ -133,6 +402,8 @@ pub fn bitreverse_usize(mut n: usize, l: usize) -> usize {
     r
 }
 
+/// Permute the array into bit-reversed order. For arrays whose length is a power of two, this is
+/// the same as [`bit_reverse_permute`], which also checks the length.
 pub fn bitreverse_order<FF>(array: &mut [FF]) {
     let mut logn = 0;
     while (1 << logn) < array.len() {
@@ -147,6 +418,31 @@ pub fn bitreverse_order<FF>(array: &mut [FF]) {
     }
 }
 
+/// The position of the element at index `i` after a [bit-reversal permutation](bit_reverse_permute)
+/// of a slice of length `2^log_2_of_n`, _i.e._, `i` with its lowest `log_2_of_n` bits reversed.
+/// Higher bits of `i` are ignored.
+#[inline]
+pub fn bit_reverse_index(i: usize, log_2_of_n: usize) -> usize {
+    bitreverse_usize(i, log_2_of_n)
+}
+
+/// Permute the slice in place such that the element at index `i` moves to index
+/// [`bit_reverse_index(i, log_2_of_n)`](bit_reverse_index), where the slice's length is
+/// `2^log_2_of_n`. This is the order [`ntt_noswap`] leaves its output in and [`intt_noswap`]
+/// expects its input in. The permutation is its own inverse.
+///
+/// # Panics
+///
+/// Panics if the slice's length is not a power of two.
+pub fn bit_reverse_permute<T>(data: &mut [T]) {
+    assert!(
+        data.len().is_power_of_two(),
+        "Length must be a power of two, but was {}.",
+        data.len()
+    );
+    bitreverse_order(data);
+}
+
 /// Compute the NTT, but leave the array in bitreversed order.
 ///
 /// This method can be expected to outperform regular NTT when
@@ -254,6 +550,120 @@ pub fn unscale(array: &mut [BFieldElement]) {
     }
 }
 
+/// Perform one FRI folding step.
+///
+/// Given the evaluations of `f(X)` on the domain generated by `omega`, return the evaluations of
+/// `f_even(X) + alpha·f_odd(X)` on the domain generated by `omega²`, where
+/// `f(X) = f_even(X²) + X·f_odd(X²)`. The result is half as long as the input.
+///
+/// The input's length must be a power of two, and `omega` a primitive root of unity of that order.
+pub fn fri_fold(
+    codeword: &[XFieldElement],
+    alpha: XFieldElement,
+    omega: BFieldElement,
+) -> Vec<XFieldElement> {
+    assert!(
+        codeword.len().is_power_of_two() && codeword.len() >= 2,
+        "Codeword length must be a power of two greater than 1, but was {}.",
+        codeword.len()
+    );
+
+    let half_len = codeword.len() / 2;
+    let two_inverse = BFieldElement::new(2).inverse();
+    let omega_inverse = omega.inverse();
+
+    let mut x_inverse = BFieldElement::one();
+    let mut folded = Vec::with_capacity(half_len);
+    for (&f_of_x, &f_of_minus_x) in codeword[..half_len].iter().zip(&codeword[half_len..]) {
+        let alpha_over_x = alpha * x_inverse;
+        let left = (XFieldElement::one() + alpha_over_x) * f_of_x;
+        let right = (XFieldElement::one() - alpha_over_x) * f_of_minus_x;
+        folded.push((left + right) * two_inverse);
+        x_inverse *= omega_inverse;
+    }
+
+    folded
+}
+
+/// Divide the codeword of some polynomial `f` by the linear polynomial `x - z`, pointwise on
+/// the evaluation domain. That is, compute the codeword of the quotient
+/// `(f(x) - f(z)) / (x - z)`, whose degree is one less than that of `f`. Here, `f` is the
+/// polynomial of degree less than the domain's length that interpolates the codeword.
+///
+/// If `z` is an element of the domain, the quotient has a removable singularity there. The
+/// quotient's value in `z` is then defined as its limit, which is `f'(z)` by L'Hôpital's rule.
+///
+/// Computes `f(z)` by interpolation. See [`divide_by_linear_on_domain_with_value`] if `f(z)`
+/// is known already.
+///
+/// # Panics
+///
+/// Panics if the lengths of `codeword` and `domain` differ, or if the `domain` contains
+/// repeated points.
+pub fn divide_by_linear_on_domain(
+    codeword: &[XFieldElement],
+    domain: &[BFieldElement],
+    z: XFieldElement,
+) -> Vec<XFieldElement> {
+    let interpolant = interpolant_on_domain(codeword, domain);
+    let f_of_z = interpolant.evaluate(&z);
+    divide_by_linear_on_domain_inner(codeword, domain, z, f_of_z, || interpolant)
+}
+
+/// Like [`divide_by_linear_on_domain`], but with a supplied `f(z)`, saving the interpolation of
+/// the codeword unless `z` is an element of the domain.
+///
+/// # Panics
+///
+/// Panics if the lengths of `codeword` and `domain` differ. If `z` is an element of the
+/// domain, also panics if the `domain` contains repeated points.
+pub fn divide_by_linear_on_domain_with_value(
+    codeword: &[XFieldElement],
+    domain: &[BFieldElement],
+    z: XFieldElement,
+    f_of_z: XFieldElement,
+) -> Vec<XFieldElement> {
+    let interpolant = || interpolant_on_domain(codeword, domain);
+    divide_by_linear_on_domain_inner(codeword, domain, z, f_of_z, interpolant)
+}
+
+/// The interpolant is only computed if `z` is an element of the domain.
+fn divide_by_linear_on_domain_inner(
+    codeword: &[XFieldElement],
+    domain: &[BFieldElement],
+    z: XFieldElement,
+    f_of_z: XFieldElement,
+    interpolant: impl FnOnce() -> Polynomial<XFieldElement>,
+) -> Vec<XFieldElement> {
+    assert_eq!(
+        codeword.len(),
+        domain.len(),
+        "Codeword and domain lengths must match."
+    );
+
+    let denominators = domain.iter().map(|&x| x.lift() - z).collect::<Vec<_>>();
+    let denominator_inverses = XFieldElement::batch_inverse(&denominators);
+    let mut quotient = codeword
+        .iter()
+        .zip(denominator_inverses)
+        .map(|(&f_of_x, denominator_inverse)| (f_of_x - f_of_z) * denominator_inverse)
+        .collect::<Vec<_>>();
+
+    if let Some(singularity) = denominators.iter().position(|d| d.is_zero()) {
+        quotient[singularity] = interpolant().formal_derivative().evaluate(&z);
+    }
+
+    quotient
+}
+
+fn interpolant_on_domain(
+    codeword: &[XFieldElement],
+    domain: &[BFieldElement],
+) -> Polynomial<XFieldElement> {
+    let domain = domain.iter().map(|x| x.lift()).collect::<Vec<_>>();
+    Polynomial::fast_interpolate(&domain, codeword)
+}
+
 #[inline]
 fn bitreverse(mut n: u32, l: u32) -> u32 {
     let mut r = 0;
@@ -278,6 +688,7 @@ mod fast_ntt_attempt_tests {
     use crate::shared_math::polynomial::Polynomial;
     use crate::shared_math::traits::PrimitiveRootOfUnity;
     use crate::shared_math::x_field_element::XFieldElement;
+    use crate::shared_math::x_field_element::EXTENSION_DEGREE;
 
     use super::*;
 
@@ -339,6 +750,145 @@ mod fast_ntt_attempt_tests {
         }
     }
 
+    #[proptest(cases = 20)]
+    fn x_field_ntt_equals_coordinate_wise_b_field_ntts(
+        #[strategy(0_u32..10)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] xfes: Vec<XFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+
+        let mut coordinates = (0..EXTENSION_DEGREE)
+            .map(|i| xfes.iter().map(|xfe| xfe.coefficients[i]).collect_vec())
+            .collect_vec();
+        for coordinate in coordinates.iter_mut() {
+            ntt(coordinate, omega, log_2_of_n);
+        }
+        let recombined = (0..xfes.len())
+            .map(|j| XFieldElement::new([0, 1, 2].map(|i| coordinates[i][j])))
+            .collect_vec();
+
+        let mut transformed_xfes = xfes;
+        ntt(&mut transformed_xfes, omega, log_2_of_n);
+        prop_assert_eq!(recombined, transformed_xfes.clone());
+
+        let mut coordinates_back = coordinates;
+        for coordinate in coordinates_back.iter_mut() {
+            intt(coordinate, omega, log_2_of_n);
+        }
+        intt(&mut transformed_xfes, omega, log_2_of_n);
+        for (j, xfe) in transformed_xfes.into_iter().enumerate() {
+            for (i, coordinate) in coordinates_back.iter().enumerate() {
+                prop_assert_eq!(coordinate[j], xfe.coefficients[i]);
+            }
+        }
+    }
+
+    fn coset_domain(log_2_of_n: u32) -> Vec<BFieldElement> {
+        let n = 1_u64 << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n).unwrap();
+        let offset = BFieldElement::generator();
+        (0..n).map(|i| offset * omega.mod_pow(i)).collect()
+    }
+
+    #[proptest(cases = 20)]
+    fn dividing_by_linear_on_domain_gives_low_degree_quotient(
+        #[strategy(1_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<XFieldElement>,
+        z: XFieldElement,
+        evaluation_point: XFieldElement,
+    ) {
+        let domain = coset_domain(log_2_of_n);
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = domain
+            .iter()
+            .map(|x| polynomial.evaluate(&x.lift()))
+            .collect_vec();
+
+        let quotient = divide_by_linear_on_domain(&codeword, &domain, z);
+        let lifted_domain = domain.iter().map(|x| x.lift()).collect_vec();
+        let quotient_polynomial = Polynomial::fast_interpolate(&lifted_domain, &quotient);
+        prop_assert!(quotient_polynomial.degree() < domain.len() as isize - 1);
+
+        let f_of_z = polynomial.evaluate(&z);
+        let lhs = quotient_polynomial.evaluate(&evaluation_point) * (evaluation_point - z);
+        let rhs = polynomial.evaluate(&evaluation_point) - f_of_z;
+        prop_assert_eq!(lhs, rhs);
+
+        let quotient_with_value =
+            divide_by_linear_on_domain_with_value(&codeword, &domain, z, f_of_z);
+        prop_assert_eq!(quotient, quotient_with_value);
+    }
+
+    #[proptest(cases = 20)]
+    fn dividing_by_linear_on_domain_with_z_in_domain_uses_derivative(
+        #[strategy(1_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<XFieldElement>,
+        #[strategy(0_usize..1 << #log_2_of_n)] z_index: usize,
+    ) {
+        let domain = coset_domain(log_2_of_n);
+        let polynomial = Polynomial::new(coefficients);
+        let codeword = domain
+            .iter()
+            .map(|x| polynomial.evaluate(&x.lift()))
+            .collect_vec();
+
+        let z = domain[z_index].lift();
+        let quotient = divide_by_linear_on_domain(&codeword, &domain, z);
+        let derivative = polynomial.formal_derivative();
+        prop_assert_eq!(derivative.evaluate(&z), quotient[z_index]);
+
+        let lifted_domain = domain.iter().map(|x| x.lift()).collect_vec();
+        let quotient_polynomial = Polynomial::fast_interpolate(&lifted_domain, &quotient);
+        prop_assert!(quotient_polynomial.degree() < domain.len() as isize - 1);
+
+        let f_of_z = codeword[z_index];
+        let quotient_with_value =
+            divide_by_linear_on_domain_with_value(&codeword, &domain, z, f_of_z);
+        prop_assert_eq!(quotient, quotient_with_value);
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths must match")]
+    fn dividing_by_linear_on_domain_of_different_length_panics() {
+        let domain = coset_domain(3);
+        let codeword = vec![XFieldElement::zero(); 4];
+        divide_by_linear_on_domain_with_value(
+            &codeword,
+            &domain,
+            XFieldElement::one(),
+            XFieldElement::zero(),
+        );
+    }
+
+    #[proptest(cases = 20)]
+    fn fri_fold_agrees_with_explicitly_split_polynomial(
+        #[strategy(1_u32..10)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << (#log_2_of_n - 1)))] low_degree_coefficients: Vec<XFieldElement>,
+        alpha: XFieldElement,
+    ) {
+        let n = 1_usize << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n as u64).unwrap();
+        let polynomial = Polynomial::new(low_degree_coefficients);
+        let codeword = (0..n)
+            .map(|i| polynomial.evaluate(&omega.mod_pow(i as u64).lift()))
+            .collect_vec();
+
+        let even_coefficients = polynomial.coefficients.iter().step_by(2);
+        let odd_coefficients = polynomial.coefficients.iter().skip(1).step_by(2);
+        let even = Polynomial::new(even_coefficients.copied().collect());
+        let odd = Polynomial::new(odd_coefficients.copied().collect());
+        let folded_polynomial = even + odd.scalar_mul(alpha);
+
+        let omega_squared = omega * omega;
+        let expected = (0..n / 2)
+            .map(|i| folded_polynomial.evaluate(&omega_squared.mod_pow(i as u64).lift()))
+            .collect_vec();
+
+        let folded = fri_fold(&codeword, alpha, omega);
+        prop_assert_eq!(n / 2, folded.len());
+        prop_assert_eq!(expected, folded);
+    }
+
     #[test]
     fn xfield_basic_test_of_chu_ntt() {
         let mut input_output = vec![
@@ -529,6 +1079,196 @@ mod fast_ntt_attempt_tests {
         }
     }
 
+    #[proptest(cases = 10)]
+    fn coset_intt_inverts_coset_ntt(
+        #[strategy(0_u32..12)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<BFieldElement>,
+        #[filter(!#offset.is_zero())] offset: BFieldElement,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        let mut values = coefficients.clone();
+        coset_ntt(&mut values, omega, log_2_of_n, offset);
+        coset_intt(&mut values, omega, log_2_of_n, offset);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[proptest(cases = 10)]
+    fn coset_ntt_agrees_with_evaluation_on_coset(
+        #[strategy(0_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<XFieldElement>,
+        #[filter(!#offset.is_zero())] offset: BFieldElement,
+    ) {
+        let n = 1 << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n).unwrap();
+        let polynomial = Polynomial::new(coefficients.clone());
+
+        let mut values = coefficients;
+        coset_ntt(&mut values, omega, log_2_of_n, offset);
+
+        let coset_evaluations = (0..n)
+            .map(|i| offset * omega.mod_pow(i))
+            .map(|point| polynomial.evaluate(&point.lift()))
+            .collect_vec();
+        prop_assert_eq!(coset_evaluations, values);
+    }
+
+    #[test]
+    fn ntt_parallel_is_identical_to_ntt() {
+        for log_2_of_n in 10..=16 {
+            assert_ntt_parallel_is_identical_to_ntt(log_2_of_n);
+        }
+    }
+
+    #[test]
+    #[ignore = "slow in debug builds"]
+    fn ntt_parallel_is_identical_to_ntt_for_large_sizes() {
+        for log_2_of_n in 17..=20 {
+            assert_ntt_parallel_is_identical_to_ntt(log_2_of_n);
+        }
+    }
+
+    fn assert_ntt_parallel_is_identical_to_ntt(log_2_of_n: u32) {
+        let n = 1 << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n).unwrap();
+        let input: Vec<BFieldElement> = random_elements(n as usize);
+
+        let mut serial = input.clone();
+        ntt(&mut serial, omega, log_2_of_n);
+        let mut parallel = input;
+        ntt_parallel(&mut parallel, omega, log_2_of_n);
+        assert_eq!(serial, parallel, "log_2_of_n: {log_2_of_n}");
+    }
+
+    #[test]
+    fn ntt_parallel_is_identical_to_ntt_for_x_field_elements() {
+        let log_2_of_n = 16;
+        let n = 1 << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n).unwrap();
+        let input: Vec<XFieldElement> = random_elements(n as usize);
+
+        let mut serial = input.clone();
+        ntt(&mut serial, omega, log_2_of_n);
+        let mut parallel = input;
+        ntt_parallel(&mut parallel, omega, log_2_of_n);
+        assert_eq!(serial, parallel);
+    }
+
+    #[proptest(cases = 10)]
+    fn ntt_batch_agrees_with_individual_ntts(
+        #[strategy(0_u32..10)] log_2_of_n: u32,
+        #[strategy(vec(vec(arb(), 1 << #log_2_of_n), 0..10))] columns: Vec<Vec<BFieldElement>>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+
+        let mut individually_transformed = columns.clone();
+        for column in individually_transformed.iter_mut() {
+            ntt(column, omega, log_2_of_n);
+        }
+
+        let mut batch_transformed = columns;
+        ntt_batch(&mut batch_transformed, omega, log_2_of_n);
+        prop_assert_eq!(individually_transformed, batch_transformed);
+    }
+
+    #[test]
+    fn reused_ntt_plan_agrees_with_ntt_and_intt() {
+        let log_2_of_n = 6;
+        let plan = NttPlan::new(log_2_of_n);
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        assert_eq!(omega, plan.omega());
+        assert_eq!(log_2_of_n, plan.log_2_of_n());
+
+        for _ in 0..1000 {
+            let input: Vec<BFieldElement> = random_elements(1 << log_2_of_n);
+
+            let mut with_plan = input.clone();
+            plan.forward(&mut with_plan);
+            let mut without_plan = input.clone();
+            ntt(&mut without_plan, omega, log_2_of_n);
+            assert_eq!(without_plan, with_plan);
+
+            plan.inverse(&mut with_plan);
+            intt(&mut without_plan, omega, log_2_of_n);
+            assert_eq!(without_plan, with_plan);
+            assert_eq!(input, with_plan);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "NTT length must be at most 2^32")]
+    fn ntt_plan_for_length_beyond_two_adicity_panics() {
+        NttPlan::new(33);
+    }
+
+    #[proptest(cases = 20)]
+    fn ntt_plan_agrees_with_ntt_and_intt_for_all_small_sizes(
+        #[strategy(0_u32..12)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] input: Vec<XFieldElement>,
+    ) {
+        let plan = NttPlan::new(log_2_of_n);
+
+        let mut with_plan = input.clone();
+        plan.forward(&mut with_plan);
+        let mut without_plan = input;
+        ntt(&mut without_plan, plan.omega(), log_2_of_n);
+        prop_assert_eq!(&without_plan, &with_plan);
+
+        plan.inverse(&mut with_plan);
+        intt(&mut without_plan, plan.omega(), log_2_of_n);
+        prop_assert_eq!(without_plan, with_plan);
+    }
+
+    #[test]
+    fn bit_reverse_index_of_small_examples() {
+        assert_eq!(0, bit_reverse_index(0, 0));
+        assert_eq!(0b100, bit_reverse_index(0b001, 3));
+        assert_eq!(0b011, bit_reverse_index(0b110, 3));
+        assert_eq!(0b1010, bit_reverse_index(0b0101, 4));
+        assert_eq!(0b0001, bit_reverse_index(0b1000, 4));
+    }
+
+    #[proptest]
+    fn bit_reverse_permutation_is_an_involution(
+        #[strategy(0_usize..12)] log_2_of_n: usize,
+        #[strategy(vec(any::<u64>(), 1 << #log_2_of_n))] data: Vec<u64>,
+    ) {
+        let mut permuted = data.clone();
+        bit_reverse_permute(&mut permuted);
+        for (i, element) in data.iter().enumerate() {
+            prop_assert_eq!(element, &permuted[bit_reverse_index(i, log_2_of_n)]);
+        }
+
+        bit_reverse_permute(&mut permuted);
+        prop_assert_eq!(data, permuted);
+    }
+
+    #[proptest(cases = 20)]
+    fn bit_reverse_permutation_reorders_output_of_ntt_noswap_like_ntt(
+        #[strategy(1_u32..10)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] data: Vec<BFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(data.len() as u64).unwrap();
+        let mut ntt_output = data.clone();
+        ntt(&mut ntt_output, omega, log_2_of_n);
+
+        let mut ntt_noswap_output = data;
+        ntt_noswap(&mut ntt_noswap_output, omega);
+        bit_reverse_permute(&mut ntt_noswap_output);
+        prop_assert_eq!(ntt_output, ntt_noswap_output);
+    }
+
+    #[test]
+    #[should_panic(expected = "Length must be a power of two")]
+    fn bit_reverse_permutation_of_slice_with_length_not_a_power_of_two_panics() {
+        bit_reverse_permute(&mut [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Length must be a power of two")]
+    fn bit_reverse_permutation_of_empty_slice_panics() {
+        bit_reverse_permute::<u64>(&mut []);
+    }
+
     #[test]
     fn test_ntt_noswap() {
         for log_size in 1..8 {
@@ -1,5 +1,7 @@
 use num_traits::{One, Zero};
+use rand::rngs::StdRng;
 use rand::Rng;
+use rand::SeedableRng;
 use rand_distr::uniform::SampleUniform;
 use rand_distr::{Distribution, Standard, Uniform};
 use std::fmt::Display;
@@ -158,6 +160,20 @@ where
     rand::thread_rng().sample_iter(Standard).take(n).collect()
 }
 
+/// Generate `n` random elements using a [`StdRng`] seeded with `seed`.
+///
+/// Like [`random_elements`], but reproducible: the same seed gives the same elements on all
+/// platforms. The elements may change when the `rand` dependency is upgraded.
+pub fn random_elements_seeded<T>(n: usize, seed: u64) -> Vec<T>
+where
+    Standard: Distribution<T>,
+{
+    StdRng::seed_from_u64(seed)
+        .sample_iter(Standard)
+        .take(n)
+        .collect()
+}
+
 pub fn random_elements_distinct<T>(n: usize) -> Vec<T>
 where
     T: PartialEq,
@@ -210,8 +226,30 @@ where
 
 #[cfg(test)]
 mod test_other {
+    use crate::shared_math::b_field_element::BFieldElement;
+    use crate::shared_math::digest::Digest;
+    use crate::shared_math::x_field_element::XFieldElement;
+
     use super::*;
 
+    fn assert_seeded_random_elements_are_deterministic<T>()
+    where
+        T: PartialEq + std::fmt::Debug,
+        Standard: Distribution<T>,
+    {
+        let elements: Vec<T> = random_elements_seeded(50, 42);
+        assert_eq!(50, elements.len());
+        assert_eq!(elements, random_elements_seeded(50, 42));
+        assert_ne!(elements, random_elements_seeded(50, 43));
+    }
+
+    #[test]
+    fn random_elements_seeded_is_deterministic() {
+        assert_seeded_random_elements_are_deterministic::<BFieldElement>();
+        assert_seeded_random_elements_are_deterministic::<XFieldElement>();
+        assert_seeded_random_elements_are_deterministic::<Digest>();
+    }
+
     #[test]
     fn log_2_ceil_test() {
         assert_eq!(4, log_2_floor(16));
@@ -17,6 +17,7 @@ use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub};
 use super::b_field_element::BFieldElement;
 use super::other::{self, log_2_ceil};
 use super::traits::{Inverse, PrimitiveRootOfUnity};
+use super::x_field_element::XFieldElement;
 
 fn degree_raw<T: Add + Div + Mul + Sub + Display + Zero>(coefficients: &[T]) -> isize {
     let mut deg = coefficients.len() as isize - 1;
@@ -76,7 +77,7 @@ impl<FF: FiniteField> Zero for Polynomial<FF> {
     }
 
     fn is_zero(&self) -> bool {
-        *self == Self::zero()
+        self.degree() == -1
     }
 }
 
@@ -260,54 +261,36 @@ where
         acc
     }
 
-    // FIXME: lhs -> &self. FIXME: Change root_order: usize into : u32.
-    pub fn fast_multiply(
-        lhs: &Self,
-        rhs: &Self,
-        primitive_root: BFieldElement,
-        root_order: usize,
-    ) -> Self {
-        assert!(
-            primitive_root.mod_pow_u32(root_order as u32).is_one(),
-            "provided primitive root must have the provided power."
-        );
-        assert!(
-            !primitive_root.mod_pow_u32(root_order as u32 / 2).is_one(),
-            "provided primitive root must be primitive in the right power."
-        );
-
-        if lhs.is_zero() || rhs.is_zero() {
+    /// Multiply `self` with `other` in `O(n·log(n))` using NTTs: both polynomials are
+    /// zero-padded to the next power of two that is at least `deg(self) + deg(other) + 1`,
+    /// transformed, multiplied pointwise, and transformed back.
+    ///
+    /// Products of small degree fall back to schoolbook multiplication.
+    #[must_use]
+    pub fn fast_multiply(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
             return Self::zero();
         }
 
-        let mut root: BFieldElement = primitive_root.to_owned();
-        let mut order = root_order;
-        let lhs_degree = lhs.degree() as usize;
-        let rhs_degree = rhs.degree() as usize;
-        let degree = lhs_degree + rhs_degree;
+        let self_degree = self.degree() as usize;
+        let other_degree = other.degree() as usize;
+        let degree = self_degree + other_degree;
 
         if degree < 8 {
-            return lhs.to_owned() * rhs.to_owned();
+            return self.to_owned() * other.to_owned();
         }
 
-        while degree < order / 2 {
-            root *= root;
-            order /= 2;
-        }
+        let order = (degree + 1).next_power_of_two();
+        let root = BFieldElement::primitive_root_of_unity(order as u64).unwrap();
+        let log_2_of_n = order.ilog2();
 
-        let mut lhs_coefficients: Vec<FF> = lhs.coefficients[0..lhs_degree + 1].to_vec();
-        let mut rhs_coefficients: Vec<FF> = rhs.coefficients[0..rhs_degree + 1].to_vec();
-        while lhs_coefficients.len() < order {
-            lhs_coefficients.push(FF::zero());
-        }
-        while rhs_coefficients.len() < order {
-            rhs_coefficients.push(FF::zero());
-        }
+        let mut lhs_coefficients: Vec<FF> = self.coefficients[0..self_degree + 1].to_vec();
+        let mut rhs_coefficients: Vec<FF> = other.coefficients[0..other_degree + 1].to_vec();
+        lhs_coefficients.resize(order, FF::zero());
+        rhs_coefficients.resize(order, FF::zero());
 
-        let lhs_log_2_of_n = log_2_floor(lhs_coefficients.len() as u128) as u32;
-        let rhs_log_2_of_n = log_2_floor(rhs_coefficients.len() as u128) as u32;
-        ntt::<FF>(&mut lhs_coefficients, root, lhs_log_2_of_n);
-        ntt::<FF>(&mut rhs_coefficients, root, rhs_log_2_of_n);
+        ntt::<FF>(&mut lhs_coefficients, root, log_2_of_n);
+        ntt::<FF>(&mut rhs_coefficients, root, log_2_of_n);
 
         let mut hadamard_product: Vec<FF> = rhs_coefficients
             .into_iter()
@@ -315,7 +298,6 @@ where
             .map(|(r, l)| r * l)
             .collect();
 
-        let log_2_of_n = log_2_floor(hadamard_product.len() as u128) as u32;
         intt::<FF>(&mut hadamard_product, root, log_2_of_n);
         hadamard_product.truncate(degree + 1);
 
@@ -336,18 +318,15 @@ where
         Self::fast_zerofier(domain)
     }
 
+    /// The monic polynomial `∏(x - r)` over all distinct `r` in the `domain`, computed as a
+    /// balanced binary product of the linear factors using [`Self::fast_multiply`], _i.e._, in
+    /// `O(n·log²(n))`.
     pub fn fast_zerofier(domain: &[FF]) -> Self {
         let dedup_domain = domain.iter().copied().unique().collect::<Vec<_>>();
-        let root_order = (dedup_domain.len() + 1).next_power_of_two();
-        let primitive_root = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-        Self::fast_zerofier_inner(&dedup_domain, primitive_root, root_order)
+        Self::fast_zerofier_inner(&dedup_domain)
     }
 
-    fn fast_zerofier_inner(
-        domain: &[FF],
-        primitive_root: BFieldElement,
-        root_order: usize,
-    ) -> Self {
+    fn fast_zerofier_inner(domain: &[FF]) -> Self {
         if domain.is_empty() {
             return Self::one();
         }
@@ -356,17 +335,15 @@ where
         }
 
         let mid_point = domain.len() / 2;
-        let left = Self::fast_zerofier_inner(&domain[..mid_point], primitive_root, root_order);
-        let right = Self::fast_zerofier_inner(&domain[mid_point..], primitive_root, root_order);
-        Self::fast_multiply(&left, &right, primitive_root, root_order)
+        let left = Self::fast_zerofier_inner(&domain[..mid_point]);
+        let right = Self::fast_zerofier_inner(&domain[mid_point..]);
+        left.fast_multiply(&right)
     }
 
-    pub fn fast_evaluate(
-        &self,
-        domain: &[FF],
-        primitive_root: BFieldElement,
-        root_order: usize,
-    ) -> Vec<FF> {
+    /// Evaluate `self` in every point of the `domain` using the subproduct-tree algorithm,
+    /// _i.e._, by recursively reducing `self` modulo the zerofiers of either half of the
+    /// domain. An empty domain gives an empty result.
+    pub fn fast_evaluate(&self, domain: &[FF]) -> Vec<FF> {
         if domain.is_empty() {
             return vec![];
         }
@@ -377,48 +354,43 @@ where
 
         let half = domain.len() / 2;
 
-        let left_zerofier = Self::fast_zerofier_inner(&domain[..half], primitive_root, root_order);
-        let right_zerofier = Self::fast_zerofier_inner(&domain[half..], primitive_root, root_order);
+        let left_zerofier = Self::fast_zerofier_inner(&domain[..half]);
+        let right_zerofier = Self::fast_zerofier_inner(&domain[half..]);
 
-        let mut left = (self.clone() % left_zerofier).fast_evaluate(
-            &domain[..half],
-            primitive_root,
-            root_order,
-        );
-        let mut right = (self.clone() % right_zerofier).fast_evaluate(
-            &domain[half..],
-            primitive_root,
-            root_order,
-        );
+        let mut left = (self.clone() % left_zerofier).fast_evaluate(&domain[..half]);
+        let mut right = (self.clone() % right_zerofier).fast_evaluate(&domain[half..]);
 
         left.append(&mut right);
         left
     }
 
-    pub fn fast_interpolate(
-        domain: &[FF],
-        values: &[FF],
-        primitive_root: BFieldElement,
-        root_order: usize,
-    ) -> Self {
+    /// The polynomial of minimal degree that evaluates to `values` on the `domain`, computed
+    /// using the subproduct-tree algorithm. Interpolating through no points gives the zero
+    /// polynomial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `domain` and `values` differ, or if the `domain` contains
+    /// repeated points.
+    pub fn fast_interpolate(domain: &[FF], values: &[FF]) -> Self {
         assert_eq!(
             domain.len(),
             values.len(),
             "Domain and values lengths must match"
         );
-        debug_assert_eq!(
-            primitive_root.mod_pow_u32(root_order as u32),
-            BFieldElement::one(),
-            "Supplied element “primitive_root” must have supplied order.\
-            Supplied element was: {primitive_root:?}\
-            Supplied order was: {root_order:?}"
-        );
-
         assert!(
-            !domain.is_empty(),
-            "Cannot fast interpolate through zero points.",
+            has_unique_elements(domain),
+            "Cannot fast interpolate through repeated domain points."
         );
 
+        if domain.is_empty() {
+            return Self::zero();
+        }
+
+        Self::fast_interpolate_inner(domain, values)
+    }
+
+    fn fast_interpolate_inner(domain: &[FF], values: &[FF]) -> Self {
         const CUTOFF_POINT_FOR_FAST_INTERPOLATION: usize = 1024;
         if domain.len() < CUTOFF_POINT_FOR_FAST_INTERPOLATION {
             return Self::lagrange_interpolate(domain, values);
@@ -426,13 +398,11 @@ where
 
         let half = domain.len() / 2;
 
-        let left_zerofier = Self::fast_zerofier_inner(&domain[..half], primitive_root, root_order);
-        let right_zerofier = Self::fast_zerofier_inner(&domain[half..], primitive_root, root_order);
+        let left_zerofier = Self::fast_zerofier_inner(&domain[..half]);
+        let right_zerofier = Self::fast_zerofier_inner(&domain[half..]);
 
-        let left_offset: Vec<FF> =
-            Self::fast_evaluate(&right_zerofier, &domain[..half], primitive_root, root_order);
-        let right_offset: Vec<FF> =
-            Self::fast_evaluate(&left_zerofier, &domain[half..], primitive_root, root_order);
+        let left_offset: Vec<FF> = right_zerofier.fast_evaluate(&domain[..half]);
+        let right_offset: Vec<FF> = left_zerofier.fast_evaluate(&domain[half..]);
 
         let left_offset_inverse = FF::batch_inversion(left_offset);
         let right_offset_inverse = FF::batch_inversion(right_offset);
@@ -447,53 +417,32 @@ where
             .map(|(n, d)| n.to_owned() * d)
             .collect();
 
-        let left_interpolant =
-            Self::fast_interpolate(&domain[..half], &left_targets, primitive_root, root_order);
-        let right_interpolant =
-            Self::fast_interpolate(&domain[half..], &right_targets, primitive_root, root_order);
+        let left_interpolant = Self::fast_interpolate_inner(&domain[..half], &left_targets);
+        let right_interpolant = Self::fast_interpolate_inner(&domain[half..], &right_targets);
 
-        let left_term = Self::fast_multiply(
-            &left_interpolant,
-            &right_zerofier,
-            primitive_root,
-            root_order,
-        );
-        let right_term = Self::fast_multiply(
-            &right_interpolant,
-            &left_zerofier,
-            primitive_root,
-            root_order,
-        );
+        let left_term = left_interpolant.fast_multiply(&right_zerofier);
+        let right_term = right_interpolant.fast_multiply(&left_zerofier);
         left_term + right_term
     }
 
-    pub fn batch_fast_interpolate(
-        domain: &[FF],
-        values_matrix: &Vec<Vec<FF>>,
-        primitive_root: BFieldElement,
-        root_order: usize,
-    ) -> Vec<Self> {
-        debug_assert_eq!(
-            primitive_root.mod_pow_u32(root_order as u32),
-            BFieldElement::one(),
-            "Supplied element “primitive_root” must have supplied order.\
-            Supplied element was: {primitive_root:?}\
-            Supplied order was: {root_order:?}"
-        );
-
+    /// Like [`fast_interpolate`](Self::fast_interpolate) for every vector of values, but
+    /// shares all computations that only depend on the `domain`.
+    pub fn batch_fast_interpolate(domain: &[FF], values_matrix: &Vec<Vec<FF>>) -> Vec<Self> {
         assert!(
-            !domain.is_empty(),
-            "Cannot fast interpolate through zero points.",
+            has_unique_elements(domain),
+            "Cannot fast interpolate through repeated domain points."
         );
 
+        if domain.is_empty() {
+            return vec![Self::zero(); values_matrix.len()];
+        }
+
         let mut zerofier_dictionary: HashMap<(FF, FF), Polynomial<FF>> = HashMap::default();
         let mut offset_inverse_dictionary: HashMap<(FF, FF), Vec<FF>> = HashMap::default();
 
         Self::batch_fast_interpolate_with_memoization(
             domain,
             values_matrix,
-            primitive_root,
-            root_order,
             &mut zerofier_dictionary,
             &mut offset_inverse_dictionary,
         )
@@ -502,8 +451,6 @@ where
     fn batch_fast_interpolate_with_memoization(
         domain: &[FF],
         values_matrix: &Vec<Vec<FF>>,
-        primitive_root: BFieldElement,
-        root_order: usize,
         zerofier_dictionary: &mut HashMap<(FF, FF), Polynomial<FF>>,
         offset_inverse_dictionary: &mut HashMap<(FF, FF), Vec<FF>>,
     ) -> Vec<Self> {
@@ -524,8 +471,7 @@ where
         let left_zerofier = match zerofier_dictionary.get(&left_key) {
             Some(z) => z.to_owned(),
             None => {
-                let left_zerofier =
-                    Self::fast_zerofier_inner(&domain[..half], primitive_root, root_order);
+                let left_zerofier = Self::fast_zerofier_inner(&domain[..half]);
                 zerofier_dictionary.insert(left_key, left_zerofier.clone());
                 left_zerofier
             }
@@ -534,8 +480,7 @@ where
         let right_zerofier = match zerofier_dictionary.get(&right_key) {
             Some(z) => z.to_owned(),
             None => {
-                let right_zerofier =
-                    Self::fast_zerofier_inner(&domain[half..], primitive_root, root_order);
+                let right_zerofier = Self::fast_zerofier_inner(&domain[half..]);
                 zerofier_dictionary.insert(right_key, right_zerofier.clone());
                 right_zerofier
             }
@@ -544,12 +489,7 @@ where
         let left_offset_inverse = match offset_inverse_dictionary.get(&left_key) {
             Some(vector) => vector.to_owned(),
             None => {
-                let left_offset: Vec<FF> = Self::fast_evaluate(
-                    &right_zerofier,
-                    &domain[..half],
-                    primitive_root,
-                    root_order,
-                );
+                let left_offset: Vec<FF> = right_zerofier.fast_evaluate(&domain[..half]);
                 let left_offset_inverse = FF::batch_inversion(left_offset);
                 offset_inverse_dictionary.insert(left_key, left_offset_inverse.clone());
                 left_offset_inverse
@@ -558,12 +498,7 @@ where
         let right_offset_inverse = match offset_inverse_dictionary.get(&right_key) {
             Some(vector) => vector.to_owned(),
             None => {
-                let right_offset: Vec<FF> = Self::fast_evaluate(
-                    &left_zerofier,
-                    &domain[half..],
-                    primitive_root,
-                    root_order,
-                );
+                let right_offset: Vec<FF> = left_zerofier.fast_evaluate(&domain[half..]);
                 let right_offset_inverse = FF::batch_inversion(right_offset);
                 offset_inverse_dictionary.insert(right_key, right_offset_inverse.clone());
                 right_offset_inverse
@@ -596,16 +531,12 @@ where
         let left_interpolants = Self::batch_fast_interpolate_with_memoization(
             &domain[..half],
             &all_left_targets,
-            primitive_root,
-            root_order,
             zerofier_dictionary,
             offset_inverse_dictionary,
         );
         let right_interpolants = Self::batch_fast_interpolate_with_memoization(
             &domain[half..],
             &all_right_targets,
-            primitive_root,
-            root_order,
             zerofier_dictionary,
             offset_inverse_dictionary,
         );
@@ -615,18 +546,8 @@ where
             .par_iter()
             .zip(right_interpolants.par_iter())
             .map(|(left_interpolant, right_interpolant)| {
-                let left_term = Self::fast_multiply(
-                    left_interpolant,
-                    &right_zerofier,
-                    primitive_root,
-                    root_order,
-                );
-                let right_term = Self::fast_multiply(
-                    right_interpolant,
-                    &left_zerofier,
-                    primitive_root,
-                    root_order,
-                );
+                let left_term = left_interpolant.fast_multiply(&right_zerofier);
+                let right_term = right_interpolant.fast_multiply(&left_zerofier);
 
                 left_term + right_term
             })
@@ -765,6 +686,19 @@ where
     }
 }
 
+impl Polynomial<BFieldElement> {
+    /// Evaluate the base-field polynomial in a point of the extension field, using Horner's
+    /// method. Avoids lifting every coefficient to an [`XFieldElement`] first.
+    pub fn evaluate_in_extension(&self, point: XFieldElement) -> XFieldElement {
+        let mut acc = XFieldElement::zero();
+        for &c in self.coefficients.iter().rev() {
+            acc = acc * point + c;
+        }
+
+        acc
+    }
+}
+
 impl<FF: FiniteField> Polynomial<FF> {
     pub const fn new(coefficients: Vec<FF>) -> Self {
         Self { coefficients }
@@ -1074,8 +1008,13 @@ impl<FF: FiniteField> Polynomial<FF> {
         Self { coefficients }
     }
 
-    /// Return (quotient, remainder)
-    pub fn divide(&self, divisor: Self) -> (Self, Self) {
+    /// Euclidean division: return `(quotient, remainder)` such that
+    /// `self == quotient * divisor + remainder` and `deg(remainder) < deg(divisor)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    pub fn divide(&self, divisor: &Self) -> (Self, Self) {
         let degree_lhs = self.degree();
         let degree_rhs = divisor.degree();
         // cannot divide by zero
@@ -1139,7 +1078,7 @@ impl<FF: FiniteField> Div for Polynomial<FF> {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        let (quotient, _): (Self, Self) = self.divide(other);
+        let (quotient, _): (Self, Self) = self.divide(&other);
         quotient
     }
 }
@@ -1148,7 +1087,7 @@ impl<FF: FiniteField> Rem for Polynomial<FF> {
     type Output = Self;
 
     fn rem(self, other: Self) -> Self {
-        let (_, remainder): (Self, Self) = self.divide(other);
+        let (_, remainder): (Self, Self) = self.divide(&other);
         remainder
     }
 }
@@ -1191,15 +1130,18 @@ impl<FF: FiniteField> Add for Polynomial<FF> {
 
 impl<FF: FiniteField> AddAssign for Polynomial<FF> {
     fn add_assign(&mut self, rhs: Self) {
-        let rhs_len = rhs.coefficients.len();
-        let self_len = self.coefficients.len();
-        for i in 0..std::cmp::min(self_len, rhs_len) {
-            self.coefficients[i] = self.coefficients[i] + rhs.coefficients[i];
+        *self += &rhs;
+    }
+}
+
+impl<FF: FiniteField> AddAssign<&Polynomial<FF>> for Polynomial<FF> {
+    fn add_assign(&mut self, rhs: &Self) {
+        if rhs.coefficients.len() > self.coefficients.len() {
+            self.coefficients.resize(rhs.coefficients.len(), FF::zero());
         }
 
-        if rhs_len > self_len {
-            self.coefficients
-                .append(&mut rhs.coefficients[self_len..].to_vec());
+        for (coefficient, &rhs_coefficient) in self.coefficients.iter_mut().zip(&rhs.coefficients) {
+            *coefficient += rhs_coefficient;
         }
     }
 }
@@ -1248,10 +1190,15 @@ impl<FF: FiniteField> Polynomial<FF> {
 }
 
 impl<FF: FiniteField> Polynomial<FF> {
+    /// The degree of the polynomial, or -1 for the zero polynomial. Trailing zero coefficients,
+    /// which arithmetic might leave in place, are ignored. Use
+    /// [`normalize`](Self::normalize) to remove them.
     pub fn degree(&self) -> isize {
         degree_raw(&self.coefficients)
     }
 
+    /// The formal derivative, mapping coefficient `c_i` of `x^i` to coefficient `i·c_i` of
+    /// `x^(i-1)`. Constant polynomials, including zero, map to zero.
     pub fn formal_derivative(&self) -> Self {
         let coefficients = self
             .clone()
@@ -1274,6 +1221,51 @@ impl<FF: FiniteField> Mul for Polynomial<FF> {
     }
 }
 
+/// Multiplication by a scalar. Multiplying by zero gives the zero polynomial, _i.e._, the one
+/// without any coefficients.
+impl<FF> Mul<BFieldElement> for Polynomial<FF>
+where
+    FF: FiniteField + Mul<BFieldElement, Output = FF>,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: BFieldElement) -> Self {
+        if scalar.is_zero() {
+            return Self::zero();
+        }
+        let coefficients = self.coefficients.into_iter().map(|c| c * scalar).collect();
+        Self { coefficients }
+    }
+}
+
+/// Multiplication by a scalar. Multiplying by zero gives the zero polynomial, _i.e._, the one
+/// without any coefficients.
+impl Mul<XFieldElement> for Polynomial<XFieldElement> {
+    type Output = Self;
+
+    fn mul(self, scalar: XFieldElement) -> Self {
+        if scalar.is_zero() {
+            return Self::zero();
+        }
+        let coefficients = self.coefficients.into_iter().map(|c| c * scalar).collect();
+        Self { coefficients }
+    }
+}
+
+/// Multiplication by a scalar from the extension field, lifting the polynomial into the
+/// extension field. Multiplying by zero gives the zero polynomial.
+impl Mul<XFieldElement> for Polynomial<BFieldElement> {
+    type Output = Polynomial<XFieldElement>;
+
+    fn mul(self, scalar: XFieldElement) -> Polynomial<XFieldElement> {
+        if scalar.is_zero() {
+            return Polynomial::zero();
+        }
+        let coefficients = self.coefficients.into_iter().map(|c| c * scalar).collect();
+        Polynomial { coefficients }
+    }
+}
+
 #[cfg(test)]
 mod test_polynomials {
     use proptest::collection::size_range;
@@ -1283,7 +1275,6 @@ mod test_polynomials {
     use test_strategy::proptest;
 
     use crate::shared_math::traits::PrimitiveRootOfUnity;
-    use crate::shared_math::x_field_element::XFieldElement;
 
     use super::*;
 
@@ -1392,6 +1383,96 @@ mod test_polynomials {
         prop_assert_eq!(expected_num_coefficients, num_coefficients);
     }
 
+    #[proptest]
+    fn evaluating_in_extension_agrees_with_evaluating_lifted_polynomial(
+        polynomial: Polynomial<BFieldElement>,
+        point: XFieldElement,
+    ) {
+        let lifted_coefficients = polynomial.coefficients.iter();
+        let lifted_coefficients = lifted_coefficients.map(|&c| XFieldElement::new_const(c));
+        let lifted_polynomial = Polynomial::new(lifted_coefficients.collect());
+
+        let expected = lifted_polynomial.evaluate(&point);
+        prop_assert_eq!(expected, polynomial.evaluate_in_extension(point));
+    }
+
+    #[proptest]
+    fn zero_polynomial_evaluates_to_zero_in_extension(point: XFieldElement) {
+        let zero = Polynomial::<BFieldElement>::zero();
+        prop_assert_eq!(XFieldElement::zero(), zero.evaluate_in_extension(point));
+    }
+
+    #[proptest]
+    fn constant_polynomial_evaluates_to_constant_in_extension(
+        constant: BFieldElement,
+        point: XFieldElement,
+    ) {
+        let polynomial = Polynomial::from_constant(constant);
+        let expected = XFieldElement::new_const(constant);
+        prop_assert_eq!(expected, polynomial.evaluate_in_extension(point));
+    }
+
+    #[proptest]
+    fn multiplying_by_zero_scalar_gives_zero_polynomial(
+        b_polynomial: Polynomial<BFieldElement>,
+        x_polynomial: Polynomial<XFieldElement>,
+    ) {
+        let b_product = b_polynomial.clone() * BFieldElement::zero();
+        prop_assert!(b_product.coefficients.is_empty());
+
+        let lifted_product = b_polynomial * XFieldElement::zero();
+        prop_assert!(lifted_product.coefficients.is_empty());
+
+        let x_product = x_polynomial.clone() * BFieldElement::zero();
+        prop_assert!(x_product.coefficients.is_empty());
+
+        let x_product = x_polynomial * XFieldElement::zero();
+        prop_assert!(x_product.coefficients.is_empty());
+    }
+
+    #[proptest]
+    fn scalar_multiplication_distributes_over_addition(
+        a: Polynomial<XFieldElement>,
+        b: Polynomial<XFieldElement>,
+        b_scalar: BFieldElement,
+        x_scalar: XFieldElement,
+    ) {
+        let sum = a.clone() + b.clone();
+        prop_assert_eq!(
+            sum.clone() * b_scalar,
+            a.clone() * b_scalar + b.clone() * b_scalar
+        );
+        prop_assert_eq!(sum * x_scalar, a * x_scalar + b * x_scalar);
+    }
+
+    #[proptest]
+    fn lifting_scalar_multiplication_agrees_with_evaluation(
+        polynomial: Polynomial<BFieldElement>,
+        scalar: XFieldElement,
+        point: XFieldElement,
+    ) {
+        let expected = polynomial.evaluate_in_extension(point) * scalar;
+        let product = polynomial * scalar;
+        prop_assert_eq!(expected, product.evaluate(&point));
+    }
+
+    #[proptest]
+    fn add_assign_by_reference_agrees_with_addition(
+        #[strategy(vec(arb(), 0..20))] a: Vec<BFieldElement>,
+        #[strategy(vec(arb(), 0..20))] b: Vec<BFieldElement>,
+    ) {
+        let a = Polynomial::new(a);
+        let b = Polynomial::new(b);
+
+        let mut sum = a.clone();
+        sum += &b;
+        prop_assert_eq!(a.clone() + b.clone(), sum.clone());
+        prop_assert_eq!(
+            a.coefficients.len().max(b.coefficients.len()),
+            sum.coefficients.len()
+        );
+    }
+
     #[proptest]
     fn slow_lagrange_interpolation(
         polynomial: Polynomial<BFieldElement>,
@@ -1731,15 +1812,13 @@ mod test_polynomials {
 
     #[proptest]
     fn fast_multiplication_by_zero_gives_zero(poly: Polynomial<BFieldElement>) {
-        let primitive_root = BFieldElement::primitive_root_of_unity(32).unwrap();
-        let product = Polynomial::fast_multiply(&Polynomial::zero(), &poly, primitive_root, 32);
+        let product = Polynomial::zero().fast_multiply(&poly);
         prop_assert_eq!(Polynomial::zero(), product);
     }
 
     #[proptest]
     fn fast_multiplication_by_one_gives_self(poly: Polynomial<BFieldElement>) {
-        let primitive_root = BFieldElement::primitive_root_of_unity(32).unwrap();
-        let product = Polynomial::fast_multiply(&Polynomial::one(), &poly, primitive_root, 32);
+        let product = Polynomial::one().fast_multiply(&poly);
         prop_assert_eq!(poly, product);
     }
 
@@ -1748,9 +1827,8 @@ mod test_polynomials {
         a: Polynomial<BFieldElement>,
         b: Polynomial<BFieldElement>,
     ) {
-        let primitive_root = BFieldElement::primitive_root_of_unity(32).unwrap();
-        let product = Polynomial::fast_multiply(&a, &b, primitive_root, 32);
-        let product_commutative = Polynomial::fast_multiply(&b, &a, primitive_root, 32);
+        let product = a.fast_multiply(&b);
+        let product_commutative = b.fast_multiply(&a);
         prop_assert_eq!(product, product_commutative);
     }
 
@@ -1759,11 +1837,29 @@ mod test_polynomials {
         a: Polynomial<BFieldElement>,
         b: Polynomial<BFieldElement>,
     ) {
-        let primitive_root = BFieldElement::primitive_root_of_unity(32).unwrap();
-        let product = Polynomial::fast_multiply(&a, &b, primitive_root, 32);
+        let product = a.fast_multiply(&b);
         prop_assert_eq!(a * b, product);
     }
 
+    #[proptest]
+    fn fast_multiplication_by_constant_scales_coefficients(
+        poly: Polynomial<BFieldElement>,
+        constant: BFieldElement,
+    ) {
+        let product = Polynomial::from_constant(constant).fast_multiply(&poly);
+        prop_assert_eq!(poly.scalar_mul(constant), product);
+    }
+
+    #[proptest(cases = 10)]
+    fn fast_multiplication_of_large_polynomials_agrees_with_naive_multiplication(
+        #[strategy(vec(arb(), 100..600))] a_coefficients: Vec<BFieldElement>,
+        #[strategy(vec(arb(), 100..600))] b_coefficients: Vec<BFieldElement>,
+    ) {
+        let a = Polynomial::new(a_coefficients);
+        let b = Polynomial::new(b_coefficients);
+        prop_assert_eq!(a.clone() * b.clone(), a.fast_multiply(&b));
+    }
+
     #[proptest(cases = 50)]
     fn naive_zerofier_and_fast_zerofier_are_identical(
         #[any(size_range(..1024).lift())] domain: Vec<BFieldElement>,
@@ -1797,6 +1893,27 @@ mod test_polynomials {
         }
     }
 
+    #[test]
+    fn zerofier_of_empty_domain_is_one() {
+        let empty_domain: [BFieldElement; 0] = [];
+        assert_eq!(Polynomial::one(), Polynomial::zerofier(&empty_domain));
+        assert_eq!(Polynomial::one(), Polynomial::naive_zerofier(&empty_domain));
+        assert_eq!(Polynomial::one(), Polynomial::fast_zerofier(&empty_domain));
+    }
+
+    #[proptest(cases = 50)]
+    fn zerofier_degree_is_number_of_base_roots(
+        #[any(size_range(..1024).lift())] base_roots: Vec<BFieldElement>,
+        #[strategy(vec(0..#base_roots.len().max(1), 0..100))] repeated_indices: Vec<usize>,
+    ) {
+        let mut roots = base_roots.clone();
+        roots.extend(repeated_indices.iter().filter_map(|&i| base_roots.get(i)));
+
+        let num_base_roots = roots.iter().unique().count() as isize;
+        prop_assert_eq!(num_base_roots, Polynomial::zerofier(&roots).degree());
+        prop_assert_eq!(num_base_roots, Polynomial::fast_zerofier(&roots).degree());
+    }
+
     #[proptest]
     fn zerofier_has_leading_coefficient_one(domain: Vec<BFieldElement>) {
         let zerofier = Polynomial::zerofier(&domain);
@@ -1815,8 +1932,7 @@ mod test_polynomials {
         let poly = polynomial(&[0, 0, 0, 1, 0, 1]);
         let domain = [6, 12].map(BFieldElement::new);
 
-        let root_of_unity = BFieldElement::primitive_root_of_unity(16).unwrap();
-        let evaluation = poly.fast_evaluate(&domain, root_of_unity, 16);
+        let evaluation = poly.fast_evaluate(&domain);
 
         let expected_0 = domain[0].mod_pow(5u64) + domain[0].mod_pow(3u64);
         assert_eq!(expected_0, evaluation[0]);
@@ -1831,9 +1947,7 @@ mod test_polynomials {
         #[any(size_range(..1024).lift())] domain: Vec<BFieldElement>,
     ) {
         let evaluations = domain.iter().map(|x| poly.evaluate(x)).collect_vec();
-        let root_order = domain.len().next_power_of_two();
-        let omega = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-        let fast_evaluations = poly.fast_evaluate(&domain, omega, root_order);
+        let fast_evaluations = poly.fast_evaluate(&domain);
         prop_assert_eq!(evaluations, fast_evaluations);
     }
 
@@ -1854,10 +1968,33 @@ mod test_polynomials {
     }
 
     #[test]
-    #[should_panic(expected = "zero points")]
-    fn fast_interpolation_through_no_points_is_impossible() {
-        let root_of_unity = BFieldElement::primitive_root_of_unity(1).unwrap();
-        let _ = Polynomial::<BFieldElement>::fast_interpolate(&[], &[], root_of_unity, 1);
+    fn fast_interpolation_through_no_points_gives_zero_polynomial() {
+        let interpolant = Polynomial::<BFieldElement>::fast_interpolate(&[], &[]);
+        assert!(interpolant.is_zero());
+
+        let evaluations = Polynomial::<BFieldElement>::one().fast_evaluate(&[]);
+        assert!(evaluations.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "repeated domain points")]
+    fn fast_interpolation_through_repeated_points_is_impossible() {
+        let domain = [1, 2, 3, 2].map(BFieldElement::new);
+        let values = [4, 5, 6, 5].map(BFieldElement::new);
+        let _ = Polynomial::fast_interpolate(&domain, &values);
+    }
+
+    #[proptest(cases = 20)]
+    fn evaluation_then_interpolation_is_identity(
+        poly: Polynomial<BFieldElement>,
+        #[any(size_range(1..2048).lift())]
+        #[filter(#domain.iter().unique().count() == #domain.len())]
+        domain: Vec<BFieldElement>,
+    ) {
+        prop_assume!(domain.len() as isize > poly.degree());
+        let evaluations = poly.fast_evaluate(&domain);
+        let interpolant = Polynomial::fast_interpolate(&domain, &evaluations);
+        prop_assert_eq!(poly, interpolant);
     }
 
     #[proptest(cases = 10)]
@@ -1869,10 +2006,7 @@ mod test_polynomials {
     ) {
         let lagrange_interpolant = Polynomial::lagrange_interpolate(&domain, &values);
 
-        let root_order = domain.len().next_power_of_two();
-        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-        let fast_interpolant =
-            Polynomial::fast_interpolate(&domain, &values, root_of_unity, root_order);
+        let fast_interpolant = Polynomial::fast_interpolate(&domain, &values);
         prop_assert_eq!(lagrange_interpolant, fast_interpolant);
     }
 
@@ -1883,10 +2017,8 @@ mod test_polynomials {
         domain: Vec<BFieldElement>,
         #[strategy(vec(arb(), #domain.len()))] values: Vec<BFieldElement>,
     ) {
-        let root_order = domain.len().next_power_of_two();
-        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-        let interpolant = Polynomial::fast_interpolate(&domain, &values, root_of_unity, root_order);
-        let evaluations = interpolant.fast_evaluate(&domain, root_of_unity, root_order);
+        let interpolant = Polynomial::fast_interpolate(&domain, &values);
+        let evaluations = interpolant.fast_evaluate(&domain);
         prop_assert_eq!(values, evaluations);
     }
 
@@ -1897,16 +2029,12 @@ mod test_polynomials {
         domain: Vec<BFieldElement>,
         #[strategy(vec(vec(arb(), #domain.len()), 0..10))] value_vecs: Vec<Vec<BFieldElement>>,
     ) {
-        let root_order = domain.len().next_power_of_two();
-        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
-
         let interpolants = value_vecs
             .iter()
-            .map(|values| Polynomial::fast_interpolate(&domain, values, root_of_unity, root_order))
+            .map(|values| Polynomial::fast_interpolate(&domain, values))
             .collect_vec();
 
-        let batched_interpolants =
-            Polynomial::batch_fast_interpolate(&domain, &value_vecs, root_of_unity, root_order);
+        let batched_interpolants = Polynomial::batch_fast_interpolate(&domain, &value_vecs);
         prop_assert_eq!(interpolants, batched_interpolants);
     }
 
@@ -1936,7 +2064,7 @@ mod test_polynomials {
         let domain =
             coset_domain_of_size_from_generator_with_offset(root_order, root_of_unity, offset);
 
-        let fast_values = polynomial.fast_evaluate(&domain, root_of_unity, root_order);
+        let fast_values = polynomial.fast_evaluate(&domain);
         let fast_coset_values = polynomial.fast_coset_evaluate(offset, root_of_unity, root_order);
         prop_assert_eq!(fast_values, fast_coset_values);
     }
@@ -1953,8 +2081,7 @@ mod test_polynomials {
         let domain =
             coset_domain_of_size_from_generator_with_offset(root_order, root_of_unity, offset);
 
-        let fast_interpolant =
-            Polynomial::fast_interpolate(&domain, &values, root_of_unity, root_order);
+        let fast_interpolant = Polynomial::fast_interpolate(&domain, &values);
         let fast_coset_interpolant =
             Polynomial::fast_coset_interpolate(offset, root_of_unity, &values);
         prop_assert_eq!(fast_interpolant, fast_coset_interpolant);
@@ -1996,10 +2123,49 @@ mod test_polynomials {
         #[filter(!#b.is_zero())] b: BFieldElement,
     ) {
         let b_poly = Polynomial::new(vec![b]);
-        let (_, remainder) = a.divide(b_poly);
+        let (_, remainder) = a.divide(&b_poly);
         prop_assert_eq!(Polynomial::zero(), remainder);
     }
 
+    #[proptest]
+    fn polynomial_division_satisfies_division_identity(
+        a: Polynomial<BFieldElement>,
+        #[filter(!#b.is_zero())] b: Polynomial<BFieldElement>,
+    ) {
+        let (quotient, remainder) = a.divide(&b);
+        prop_assert!(remainder.degree() < b.degree());
+        prop_assert_eq!(a, quotient * b + remainder);
+    }
+
+    #[proptest]
+    fn dividing_by_linear_factor_of_root_is_exact(
+        a: Polynomial<BFieldElement>,
+        root: BFieldElement,
+    ) {
+        let linear_factor = Polynomial::new(vec![-root, BFieldElement::one()]);
+        let product = a.clone() * linear_factor.clone();
+        let (quotient, remainder) = product.divide(&linear_factor);
+        prop_assert_eq!(a, quotient);
+        prop_assert!(remainder.is_zero());
+    }
+
+    #[proptest]
+    fn dividing_lower_degree_dividend_gives_zero_quotient_and_dividend_as_remainder(
+        a: Polynomial<BFieldElement>,
+        #[filter(#b.degree() > #a.degree())] b: Polynomial<BFieldElement>,
+    ) {
+        let (quotient, remainder) = a.divide(&b);
+        prop_assert!(quotient.is_zero());
+        prop_assert_eq!(a, remainder);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide polynomial by zero")]
+    fn dividing_by_zero_polynomial_panics() {
+        let a = Polynomial::new(vec![BFieldElement::one()]);
+        let _ = a.divide(&Polynomial::zero());
+    }
+
     #[test]
     fn polynomial_division_by_and_with_shah_polynomial() {
         let polynomial =
@@ -2007,12 +2173,12 @@ mod test_polynomials {
 
         let shah = XFieldElement::shah_polynomial();
         let x_to_the_3 = polynomial(&[1]).shift_coefficients(3);
-        let (shah_div_x_to_the_3, shah_mod_x_to_the_3) = shah.divide(x_to_the_3);
+        let (shah_div_x_to_the_3, shah_mod_x_to_the_3) = shah.divide(&x_to_the_3);
         assert_eq!(polynomial(&[1]), shah_div_x_to_the_3);
         assert_eq!(polynomial(&[1, BFieldElement::P - 1]), shah_mod_x_to_the_3);
 
         let x_to_the_6 = polynomial(&[1]).shift_coefficients(6);
-        let (x_to_the_6_div_shah, x_to_the_6_mod_shah) = x_to_the_6.divide(shah);
+        let (x_to_the_6_div_shah, x_to_the_6_mod_shah) = x_to_the_6.divide(&shah);
 
         // x^3 + x - 1
         let expected_quot = polynomial(&[BFieldElement::P - 1, 1, 0, 1]);
@@ -2179,6 +2345,35 @@ mod test_polynomials {
         prop_assert!(!constant_polynomial.is_one());
     }
 
+    #[proptest]
+    fn degree_ignores_trailing_zeros_left_by_arithmetic(root: BFieldElement) {
+        let x = Polynomial::new(vec![BFieldElement::zero(), BFieldElement::one()]);
+        let r = Polynomial::from_constant(root);
+        let difference_of_squares = (x.clone() - r.clone()) * (x.clone() + r.clone());
+        let expanded = x.clone() * x - r.clone() * r;
+
+        let zero = difference_of_squares - expanded;
+        prop_assert_eq!(-1, zero.degree());
+        prop_assert!(zero.is_zero());
+        prop_assert_eq!(Polynomial::zero(), zero);
+    }
+
+    #[proptest]
+    fn degree_and_is_zero_are_unaffected_by_trailing_zeros(
+        polynomial: Polynomial<BFieldElement>,
+        #[strategy(0usize..30)] num_trailing_zeros: usize,
+    ) {
+        let mut coefficients = polynomial.coefficients.clone();
+        coefficients.extend(vec![BFieldElement::zero(); num_trailing_zeros]);
+        let polynomial_with_trailing_zeros = Polynomial::new(coefficients);
+
+        prop_assert_eq!(polynomial.degree(), polynomial_with_trailing_zeros.degree());
+        prop_assert_eq!(
+            polynomial.is_zero(),
+            polynomial_with_trailing_zeros.is_zero()
+        );
+    }
+
     #[test]
     fn formal_derivative_of_zero_is_zero() {
         assert!(Polynomial::<BFieldElement>::zero()
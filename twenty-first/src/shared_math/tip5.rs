@@ -574,6 +574,24 @@ impl Tip5 {
         // squeeze once
         sponge.state[..DIGEST_LENGTH].try_into().unwrap()
     }
+
+    /// Hash [`DIGEST_LENGTH`] elements, for example, the values of a single [`Digest`].
+    /// Equivalent to [`hash_fixed::<DIGEST_LENGTH>`](Self::hash_fixed).
+    pub fn hash_5(input: &[BFieldElement; DIGEST_LENGTH]) -> Digest {
+        Self::hash_fixed(input)
+    }
+
+    /// Hash an array of `N` elements.
+    ///
+    /// If `N` equals [`RATE`], this is [`hash_10`](Self::hash_10). Otherwise, the input is padded
+    /// to the sponge's rate like in [`hash_varlen`](AlgebraicHasher::hash_varlen), making inputs of
+    /// different lengths hash to different digests.
+    pub fn hash_fixed<const N: usize>(input: &[BFieldElement; N]) -> Digest {
+        match input.as_slice().try_into() {
+            Ok(rate_sized_input) => Digest::new(Self::hash_10(rate_sized_input)),
+            Err(_) => Self::hash_varlen(input),
+        }
+    }
 }
 
 impl AlgebraicHasher for Tip5 {
@@ -874,6 +892,32 @@ pub(crate) mod tip5_tests {
         Digest::new((&squeeze_result[..DIGEST_LENGTH]).try_into().unwrap())
     }
 
+    #[proptest]
+    fn hash_fixed_of_rate_many_elements_is_hash_10(input: [BFieldElement; RATE]) {
+        let digest = Digest::new(Tip5::hash_10(&input));
+        prop_assert_eq!(digest, Tip5::hash_fixed(&input));
+    }
+
+    #[proptest]
+    fn hash_5_is_hash_fixed_of_digest_length(input: [BFieldElement; DIGEST_LENGTH]) {
+        prop_assert_eq!(Tip5::hash_fixed(&input), Tip5::hash_5(&input));
+    }
+
+    #[test]
+    fn hash_fixed_of_zero_padded_inputs_of_different_lengths_differ() {
+        let zero = BFieldElement::zero();
+        let digests = [
+            Tip5::hash_fixed::<0>(&[]),
+            Tip5::hash_fixed(&[zero; 1]),
+            Tip5::hash_fixed(&[zero; 5]),
+            Tip5::hash_fixed(&[zero; 9]),
+            Tip5::hash_fixed(&[zero; 10]),
+            Tip5::hash_fixed(&[zero; 11]),
+            Tip5::hash_fixed(&[zero; 20]),
+        ];
+        assert!(digests.iter().all_unique());
+    }
+
     #[test]
     fn hash_var_len_equivalence_corner_cases() {
         for preimage_length in 0..=11 {
@@ -0,0 +1,135 @@
+use rand::rand_core::impls;
+use rand::Error;
+use rand::RngCore;
+use rand::SeedableRng;
+
+use crate::shared_math::b_field_element::BFieldElement;
+use crate::shared_math::b_field_element::BFIELD_ONE;
+use crate::shared_math::b_field_element::BFIELD_ZERO;
+use crate::shared_math::tip5::Tip5;
+use crate::shared_math::tip5::RATE;
+use crate::util_types::algebraic_hasher::Sponge;
+
+/// A deterministic random number generator backed by the [`Tip5`] sponge.
+///
+/// The seed is absorbed into a fresh sponge, and randomness is produced by squeezing it. From
+/// every squeezed [`BFieldElement`], only the lower 32 bits of its canonical representation are
+/// used, which are uniformly distributed up to a bias of about 2^-32.
+///
+/// Not suitable where the seed must remain secret from an observer of many outputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tip5Rng {
+    sponge: Tip5,
+    buffer: [u32; RATE],
+    next_index: usize,
+}
+
+impl Tip5Rng {
+    fn refill(&mut self) {
+        let squeezed = self.sponge.squeeze();
+        self.buffer = squeezed.map(|element| element.value() as u32);
+        self.next_index = 0;
+    }
+}
+
+impl SeedableRng for Tip5Rng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        const NUM_SEED_ELEMENTS: usize = 8;
+
+        let mut input = [BFIELD_ZERO; RATE];
+        for (element, chunk) in input.iter_mut().zip(seed.chunks_exact(4)) {
+            let limb = u32::from_le_bytes(chunk.try_into().unwrap());
+            *element = BFieldElement::new(limb.into());
+        }
+        input[NUM_SEED_ELEMENTS] = BFIELD_ONE;
+
+        let mut sponge = Tip5::init();
+        sponge.absorb(input);
+
+        Self {
+            sponge,
+            buffer: [0; RATE],
+            next_index: RATE,
+        }
+    }
+}
+
+impl RngCore for Tip5Rng {
+    fn next_u32(&mut self) -> u32 {
+        if self.next_index == RATE {
+            self.refill();
+        }
+        let next = self.buffer[self.next_index];
+        self.next_index += 1;
+        next
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tip5_rng_tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use test_strategy::proptest;
+
+    use super::*;
+
+    #[proptest]
+    fn same_seed_reproduces_same_stream(seed: [u8; 32]) {
+        let mut rng = Tip5Rng::from_seed(seed);
+        let mut same_rng = Tip5Rng::from_seed(seed);
+        let stream = (0..100).map(|_| rng.next_u64()).collect_vec();
+        let same_stream = (0..100).map(|_| same_rng.next_u64()).collect_vec();
+        prop_assert_eq!(stream, same_stream);
+    }
+
+    #[proptest]
+    fn different_seeds_give_different_streams(
+        seed: [u8; 32],
+        #[filter(#seed != #other_seed)] other_seed: [u8; 32],
+    ) {
+        let mut rng = Tip5Rng::from_seed(seed);
+        let mut other_rng = Tip5Rng::from_seed(other_seed);
+        let stream = (0..10).map(|_| rng.next_u64()).collect_vec();
+        let other_stream = (0..10).map(|_| other_rng.next_u64()).collect_vec();
+        prop_assert_ne!(stream, other_stream);
+    }
+
+    #[test]
+    fn bytes_pass_chi_square_uniformity_smoke_test() {
+        const SAMPLES_PER_BUCKET: usize = 1000;
+
+        let mut rng = Tip5Rng::seed_from_u64(0);
+        let mut bytes = vec![0; 256 * SAMPLES_PER_BUCKET];
+        rng.fill_bytes(&mut bytes);
+
+        let mut buckets = [0_usize; 256];
+        for byte in bytes {
+            buckets[byte as usize] += 1;
+        }
+
+        let expected = SAMPLES_PER_BUCKET as f64;
+        let chi_square: f64 = buckets
+            .iter()
+            .map(|&observed| (observed as f64 - expected).powi(2) / expected)
+            .sum();
+
+        // critical value for 255 degrees of freedom at significance level 0.001
+        let critical_value = 330.5;
+        assert!(chi_square < critical_value, "χ² = {chi_square}");
+    }
+}
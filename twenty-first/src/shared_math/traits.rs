@@ -119,6 +119,33 @@ pub trait FiniteField:
         res
     }
 
+    /// Montgomery Batch Inversion, tolerating zeros.
+    ///
+    /// Like [`batch_inversion`](Self::batch_inversion), but zeros in the input map to zero in
+    /// the output instead of causing a panic. Uses one inversion and `3n` multiplications.
+    fn batch_inverse(elements: &[Self]) -> Vec<Self> {
+        let mut scratch = Vec::with_capacity(elements.len());
+        let mut acc = Self::one();
+        for &element in elements {
+            scratch.push(acc);
+            if !element.is_zero() {
+                acc *= element;
+            }
+        }
+
+        let mut acc_inv = acc.inverse();
+        let mut res = vec![Self::zero(); elements.len()];
+        for (i, &element) in elements.iter().enumerate().rev() {
+            if element.is_zero() {
+                continue;
+            }
+            res[i] = acc_inv * scratch[i];
+            acc_inv *= element;
+        }
+
+        res
+    }
+
     #[inline(always)]
     fn square(self) -> Self {
         self * self
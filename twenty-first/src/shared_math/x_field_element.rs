@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::iter::Product;
 use std::iter::Sum;
 use std::ops::Add;
 use std::ops::AddAssign;
@@ -11,13 +12,16 @@ use std::ops::SubAssign;
 
 use arbitrary::Arbitrary;
 use bfieldcodec_derive::BFieldCodec;
+use num_bigint::BigUint;
 use num_traits::One;
 use num_traits::Zero;
 use rand::Rng;
 use rand_distr::Distribution;
 use rand_distr::Standard;
+use rayon::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::error::TryFromXFieldElementError;
 use crate::shared_math::b_field_element::BFieldElement;
@@ -84,6 +88,24 @@ impl Sum for XFieldElement {
     }
 }
 
+impl<'a> Sum<&'a XFieldElement> for XFieldElement {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for XFieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|a, b| a * b).unwrap_or_else(XFieldElement::one)
+    }
+}
+
+impl<'a> Product<&'a XFieldElement> for XFieldElement {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
 impl<T> From<T> for XFieldElement
 where
     T: Into<BFieldElement>,
@@ -114,7 +136,7 @@ impl From<XFieldElement> for Polynomial<BFieldElement> {
 
 impl From<Polynomial<BFieldElement>> for XFieldElement {
     fn from(poly: Polynomial<BFieldElement>) -> Self {
-        let (_, rem) = poly.divide(Self::shah_polynomial());
+        let (_, rem) = poly.divide(&Self::shah_polynomial());
         let zero = BFieldElement::zero();
         let mut rem_arr: [BFieldElement; EXTENSION_DEGREE] = [zero; EXTENSION_DEGREE];
 
@@ -161,6 +183,48 @@ impl XFieldElement {
         Self { coefficients }
     }
 
+    /// Construct an extension field element from its coefficients, constant term first. That
+    /// is, `[a, b, c]` corresponds to `a + b·x + c·x²`. Inverse of
+    /// [`coefficients`](Self::coefficients).
+    #[inline]
+    pub const fn from_coefficients(coefficients: [BFieldElement; EXTENSION_DEGREE]) -> Self {
+        Self::new(coefficients)
+    }
+
+    /// The coefficients of the extension field element, constant term first. That is,
+    /// `a + b·x + c·x²` gives `[a, b, c]`. Inverse of
+    /// [`from_coefficients`](Self::from_coefficients).
+    #[inline]
+    pub const fn coefficients(&self) -> [BFieldElement; EXTENSION_DEGREE] {
+        self.coefficients
+    }
+
+    /// The raw Montgomery representation of the coefficients, constant term first. Inverse of
+    /// [`from_raw`](Self::from_raw), _i.e._, `XFieldElement::from_raw(x.raw()) == x` for every
+    /// `x`.
+    ///
+    /// The raw representation is **not** canonical: it depends on internal implementation
+    /// details and may change between versions. Only use it for exchanging elements with
+    /// trusted parties using the same version of this library. For a canonical serialization,
+    /// see [`BFieldCodec`](crate::shared_math::bfield_codec::BFieldCodec).
+    #[inline]
+    pub fn raw(&self) -> [u64; EXTENSION_DEGREE] {
+        self.coefficients.map(|coefficient| coefficient.raw_u64())
+    }
+
+    /// Construct an extension field element from the raw Montgomery representation of its
+    /// coefficients, as returned by [`raw`](Self::raw). No validation takes place; raw values
+    /// that did not originate from [`raw`](Self::raw) might lead to non-reduced internal
+    /// representations.
+    #[inline]
+    pub const fn from_raw(raw: [u64; EXTENSION_DEGREE]) -> Self {
+        Self::new([
+            BFieldElement::from_raw_u64(raw[0]),
+            BFieldElement::from_raw_u64(raw[1]),
+            BFieldElement::from_raw_u64(raw[2]),
+        ])
+    }
+
     #[inline]
     pub const fn new_u64(coeffs: [u64; EXTENSION_DEGREE]) -> Self {
         Self {
@@ -202,6 +266,21 @@ impl XFieldElement {
         XFieldElement::new([elements[2], elements[3], elements[4]])
     }
 
+    /// Raise `self` to the power of an arbitrarily large exponent, using square-and-multiply.
+    /// In particular, `x^0` is one for any `x`, including zero.
+    #[must_use]
+    pub fn mod_pow_biguint(&self, exponent: &BigUint) -> Self {
+        let mut result = Self::one();
+        for i in (0..exponent.bits()).rev() {
+            result = result.square();
+            if exponent.bit(i) {
+                result *= *self;
+            }
+        }
+
+        result
+    }
+
     // `increment` and `decrement` are mainly used for testing purposes
     pub fn increment(&mut self, index: usize) {
         self.coefficients[index].increment();
@@ -210,6 +289,28 @@ impl XFieldElement {
     pub fn decrement(&mut self, index: usize) {
         self.coefficients[index].decrement();
     }
+
+    /// Reduce the internal representation of all coefficients of all elements in place, using
+    /// multiple threads. The extension-field analog of
+    /// [`BFieldElement::reduce_slice_parallel`].
+    pub fn reduce_slice_parallel(elements: &mut [Self]) {
+        elements
+            .par_chunks_mut(BFieldElement::PARALLEL_CHUNK_SIZE)
+            .for_each(|chunk| {
+                for element in chunk {
+                    element.coefficients = element.coefficients.map(BFieldElement::reduced);
+                }
+            });
+    }
+}
+
+/// Overwrites all coefficients with zero, clearing the internal storage. See
+/// [`BFieldElement`'s implementation](BFieldElement#impl-Zeroize-for-BFieldElement) for wiping
+/// secrets on drop.
+impl Zeroize for XFieldElement {
+    fn zeroize(&mut self) {
+        self.coefficients.zeroize();
+    }
 }
 
 impl Emojihash for XFieldElement {
@@ -494,6 +595,70 @@ impl Sub<XFieldElement> for BFieldElement {
     }
 }
 
+/// Reference variants of the arithmetic operators, avoiding copies in accumulation loops.
+impl Add<&XFieldElement> for &XFieldElement {
+    type Output = XFieldElement;
+
+    #[inline]
+    fn add(self, other: &XFieldElement) -> XFieldElement {
+        *self + *other
+    }
+}
+
+impl Add<&XFieldElement> for XFieldElement {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &XFieldElement) -> Self {
+        self + *other
+    }
+}
+
+impl Sub<&XFieldElement> for &XFieldElement {
+    type Output = XFieldElement;
+
+    #[inline]
+    fn sub(self, other: &XFieldElement) -> XFieldElement {
+        *self - *other
+    }
+}
+
+impl Sub<&XFieldElement> for XFieldElement {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &XFieldElement) -> Self {
+        self - *other
+    }
+}
+
+impl Mul<&XFieldElement> for &XFieldElement {
+    type Output = XFieldElement;
+
+    #[inline]
+    fn mul(self, other: &XFieldElement) -> XFieldElement {
+        *self * *other
+    }
+}
+
+impl Mul<&XFieldElement> for XFieldElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &XFieldElement) -> Self {
+        self * *other
+    }
+}
+
+impl Neg for &XFieldElement {
+    type Output = XFieldElement;
+
+    #[inline]
+    fn neg(self) -> XFieldElement {
+        -*self
+    }
+}
+
 impl AddAssign<XFieldElement> for XFieldElement {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -604,6 +769,116 @@ mod x_field_element_test {
         type Strategy = BoxedStrategy<Self>;
     }
 
+    #[proptest]
+    fn sum_and_product_agree_with_folds(elements: Vec<XFieldElement>) {
+        let sum = elements
+            .iter()
+            .fold(XFieldElement::zero(), |acc, &e| acc + e);
+        let product = elements
+            .iter()
+            .fold(XFieldElement::one(), |acc, &e| acc * e);
+
+        prop_assert_eq!(sum, elements.iter().sum::<XFieldElement>());
+        prop_assert_eq!(sum, elements.iter().copied().sum::<XFieldElement>());
+        prop_assert_eq!(product, elements.iter().product::<XFieldElement>());
+        prop_assert_eq!(product, elements.into_iter().product::<XFieldElement>());
+    }
+
+    #[test]
+    fn sum_and_product_of_empty_iterator_are_identities() {
+        let no_elements: [XFieldElement; 0] = [];
+        assert!(no_elements.iter().sum::<XFieldElement>().is_zero());
+        assert!(no_elements.iter().product::<XFieldElement>().is_one());
+    }
+
+    #[proptest]
+    fn zeroized_element_is_zero(mut element: XFieldElement) {
+        element.zeroize();
+        prop_assert_eq!(XFieldElement::zero(), element);
+        prop_assert_eq!([0; EXTENSION_DEGREE], element.raw());
+    }
+
+    #[proptest]
+    fn raw_representation_round_trips(element: XFieldElement) {
+        prop_assert_eq!(element, XFieldElement::from_raw(element.raw()));
+    }
+
+    #[test]
+    fn raw_representation_differs_from_canonical_representation() {
+        let element = XFieldElement::new_u64([1, 2, 3]);
+        let raw_bytes = element.raw().map(u64::to_le_bytes);
+        let canonical_bytes = element.coefficients.map(|c| c.to_canonical_bytes());
+        assert_ne!(canonical_bytes, raw_bytes);
+        assert_eq!(element, XFieldElement::from_raw(element.raw()));
+    }
+
+    #[proptest]
+    fn parallel_reduction_agrees_with_serial_reduction(raw_values: Vec<[u64; EXTENSION_DEGREE]>) {
+        let elements = raw_values
+            .into_iter()
+            .map(|raw| XFieldElement::new(raw.map(BFieldElement::from_raw_u64)))
+            .collect_vec();
+        let serially_reduced = elements
+            .iter()
+            .map(|e| XFieldElement::new(e.coefficients.map(BFieldElement::reduced)))
+            .collect_vec();
+        let mut reduced = elements.clone();
+        XFieldElement::reduce_slice_parallel(&mut reduced);
+        prop_assert_eq!(&serially_reduced, &reduced);
+
+        for (element, reduced_element) in elements.iter().zip_eq(&reduced) {
+            let coefficients = element.coefficients.iter();
+            let reduced_coefficients = reduced_element.coefficients.iter();
+            for (c, reduced_c) in coefficients.zip_eq(reduced_coefficients) {
+                prop_assert!(reduced_c.raw_u64() < BFieldElement::P);
+                prop_assert_eq!(c.value(), reduced_c.value());
+            }
+        }
+    }
+
+    #[proptest]
+    fn reducing_results_of_arithmetic_changes_nothing(
+        #[strategy(prop::collection::vec(arb(), 0..100))] elements: Vec<XFieldElement>,
+    ) {
+        let squares = elements.iter().map(|&e| e * e).collect_vec();
+        let mut reduced_squares = squares.clone();
+        XFieldElement::reduce_slice_parallel(&mut reduced_squares);
+        prop_assert_eq!(squares, reduced_squares);
+    }
+
+    #[proptest]
+    fn reference_operators_agree_with_value_operators(a: XFieldElement, b: XFieldElement) {
+        prop_assert_eq!(a + b, &a + &b);
+        prop_assert_eq!(a - b, &a - &b);
+        prop_assert_eq!(a * b, &a * &b);
+        prop_assert_eq!(a + b, a + &b);
+        prop_assert_eq!(a - b, a - &b);
+        prop_assert_eq!(a * b, a * &b);
+        prop_assert_eq!(-a, -&a);
+    }
+
+    #[proptest]
+    fn reference_based_accumulation_agrees_with_value_based_accumulation(
+        #[strategy(prop::collection::vec(arb(), 0..50))] elements: Vec<XFieldElement>,
+    ) {
+        let mut value_sum = XFieldElement::zero();
+        let mut value_product = XFieldElement::one();
+        for &element in &elements {
+            value_sum = value_sum + element - element * element;
+            value_product = value_product * element;
+        }
+
+        let mut reference_sum = XFieldElement::zero();
+        let mut reference_product = XFieldElement::one();
+        for element in &elements {
+            reference_sum = reference_sum + element - &(element * element);
+            reference_product = reference_product * element;
+        }
+
+        prop_assert_eq!(value_sum, reference_sum);
+        prop_assert_eq!(value_product, reference_product);
+    }
+
     #[test]
     fn one_zero_test() {
         let one = XFieldElement::one();
@@ -1146,6 +1421,55 @@ mod x_field_element_test {
         }
     }
 
+    #[proptest]
+    fn mod_pow_biguint_corresponds_to_repeated_multiplication(
+        base: XFieldElement,
+        #[strategy(0_u32..200)] exponent: u32,
+    ) {
+        let mut expected = XFieldElement::one();
+        for _ in 0..exponent {
+            expected *= base;
+        }
+        let exponent = BigUint::from(exponent);
+        prop_assert_eq!(expected, base.mod_pow_biguint(&exponent));
+    }
+
+    #[proptest]
+    fn mod_pow_biguint_edge_cases(base: XFieldElement) {
+        prop_assert!(base.mod_pow_biguint(&BigUint::zero()).is_one());
+        prop_assert_eq!(base, base.mod_pow_biguint(&BigUint::one()));
+    }
+
+    #[proptest]
+    fn mod_pow_biguint_by_multiplicative_group_order_is_one(
+        #[filter(!#base.is_zero())] base: XFieldElement,
+    ) {
+        let p = BigUint::from(BFieldElement::P);
+        let group_order = p.pow(EXTENSION_DEGREE as u32) - 1_u32;
+        prop_assert!(base.mod_pow_biguint(&group_order).is_one());
+    }
+
+    #[proptest]
+    fn coefficients_and_from_coefficients_are_inverses(xfe: XFieldElement) {
+        let coefficients = xfe.coefficients();
+        prop_assert_eq!(xfe, XFieldElement::from_coefficients(coefficients));
+    }
+
+    #[test]
+    fn coefficients_are_ordered_constant_term_first() {
+        let x = XFieldElement::new_u64([0, 1, 0]);
+        let xfe = XFieldElement::new_u64([3, 0, 0]) + x * x * XFieldElement::new_u64([5, 0, 0]);
+        assert_eq!([3, 0, 5].map(BFieldElement::new), xfe.coefficients());
+    }
+
+    #[proptest]
+    fn arithmetic_on_reconstructed_elements_matches(a: XFieldElement, b: XFieldElement) {
+        let reconstructed_a = XFieldElement::from_coefficients(a.coefficients());
+        let reconstructed_b = XFieldElement::from_coefficients(b.coefficients());
+        prop_assert_eq!(a + b, reconstructed_a + reconstructed_b);
+        prop_assert_eq!(a * b, reconstructed_a * reconstructed_b);
+    }
+
     #[test]
     fn x_field_mod_pow_test() {
         let const_poly = XFieldElement::new([3, 0, 0].map(BFieldElement::new));
@@ -1213,6 +1537,34 @@ mod x_field_element_test {
         }
     }
 
+    #[proptest]
+    fn batch_inverse_agrees_with_inverse_or_zero(
+        #[strategy(prop::collection::vec((any::<bool>(), arb::<XFieldElement>()), 0..100))]
+        input: Vec<(bool, XFieldElement)>,
+    ) {
+        let xfes = input
+            .into_iter()
+            .map(|(is_zero, xfe)| if is_zero { XFieldElement::zero() } else { xfe })
+            .collect_vec();
+        let xfes_inv = XFieldElement::batch_inverse(&xfes);
+        prop_assert_eq!(xfes.len(), xfes_inv.len());
+        for (xfe, xfe_inv) in izip!(xfes, xfes_inv) {
+            prop_assert_eq!(xfe.inverse_or_zero(), xfe_inv);
+        }
+    }
+
+    #[test]
+    fn batch_inverse_of_mixed_zero_and_nonzero_xfes() {
+        let zero = XFieldElement::zero();
+        let x = XFieldElement::new([0, 1, 0].map(BFieldElement::new));
+        let lifted_two = XFieldElement::new_const(BFieldElement::new(2));
+        let xfes = [zero, x, zero, lifted_two, zero];
+
+        let expected = [zero, x.inverse(), zero, lifted_two.inverse(), zero];
+        assert_eq!(expected.to_vec(), XFieldElement::batch_inverse(&xfes));
+        assert!(XFieldElement::batch_inverse(&[]).is_empty());
+    }
+
     #[test]
     fn inverse_or_zero_xfe() {
         let zero = XFieldElement::zero();
@@ -7,6 +7,10 @@
 //! In particular:
 //!  - [`RustyLevelDbVec`](storage_vec::RustyLevelDbVec) provides a database-backed Vec with
 //!    read/write cache and atomic writes.
+//!  - `SledVec` provides the same on top of the pure-Rust `sled` database. It is only available
+//!    with the `sled` feature.
+//!  - [`RustyLevelDbMap`](storage_map::RustyLevelDbMap) provides the same for a database-backed
+//!    HashMap.
 //!  - [`SimpleRustyStorage`](storage_schema::SimpleRustyStorage) provides atomic DB writes across
 //!    any number of [`DbtVec`](storage_schema::DbtVec) or [`DbtSingleton`](storage_schema::DbtSingleton) "tables".
 //!  - [`DatabaseArray`](database_array::DatabaseArray) and [`DatabaseVector`](database_vector::DatabaseVector) provide uncached
@@ -59,6 +63,7 @@
 pub mod database_array;
 pub mod database_vector;
 pub mod level_db;
+pub mod storage_map;
 pub mod storage_schema;
 pub mod storage_vec;
 
@@ -0,0 +1,123 @@
+//! Provides a DB backed HashMap API that is thread-safe, cached, and atomic
+
+#![allow(missing_docs)]
+mod rusty_leveldb_map;
+mod rusty_leveldb_map_private;
+pub mod traits;
+
+pub use rusty_leveldb_map::*;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use itertools::Itertools;
+    use leveldb::batch::WriteBatch;
+
+    use super::traits::*;
+    use super::*;
+    use crate::storage::level_db::DB;
+
+    fn get_test_db() -> DB {
+        DB::open_new_test_database(true, None, None, None).unwrap()
+    }
+
+    #[test]
+    fn insert_get_remove_round_trip() {
+        let db = get_test_db();
+        let mut map: RustyLevelDbMap<String, u64> = RustyLevelDbMap::new(db, 0, "test map");
+        assert!(map.is_empty());
+        assert_eq!(None, map.get(&"foo".to_string()));
+
+        assert_eq!(None, map.insert("foo".to_string(), 17));
+        assert_eq!(None, map.insert("bar".to_string(), 42));
+        assert_eq!(2, map.len());
+        assert!(map.contains_key(&"foo".to_string()));
+        assert_eq!(Some(17), map.get(&"foo".to_string()));
+
+        assert_eq!(Some(17), map.insert("foo".to_string(), 1776));
+        assert_eq!(2, map.len());
+        assert_eq!(Some(1776), map.get(&"foo".to_string()));
+
+        assert_eq!(Some(1776), map.remove(&"foo".to_string()));
+        assert_eq!(None, map.remove(&"foo".to_string()));
+        assert!(!map.contains_key(&"foo".to_string()));
+        assert_eq!(1, map.len());
+    }
+
+    #[test]
+    fn pull_queue_persists_all_pending_writes_in_one_batch() {
+        let mut db = get_test_db();
+        let mut map: RustyLevelDbMap<u64, u64> = RustyLevelDbMap::new(db.clone(), 0, "test map");
+        for i in 0..100 {
+            map.insert(i, i * i);
+        }
+        map.remove(&3);
+        map.insert(7, 1337);
+
+        let write_batch = WriteBatch::new();
+        map.pull_queue(&write_batch);
+
+        // nothing is persisted before the batch is written
+        let reloaded_map: RustyLevelDbMap<u64, u64> =
+            RustyLevelDbMap::new(db.clone(), 0, "test map");
+        assert!(reloaded_map.is_empty());
+        assert_eq!(None, reloaded_map.get(&7));
+
+        assert!(db.write_auto(&write_batch).is_ok());
+        let reloaded_map: RustyLevelDbMap<u64, u64> = RustyLevelDbMap::new(db, 0, "test map");
+        assert_eq!(99, reloaded_map.len());
+        assert_eq!(99, reloaded_map.persisted_len());
+        assert_eq!(None, reloaded_map.get(&3));
+        assert_eq!(Some(1337), reloaded_map.get(&7));
+        assert_eq!(Some(81), reloaded_map.get(&9));
+    }
+
+    #[test]
+    fn iter_merges_persisted_and_pending_entries() {
+        let mut db = get_test_db();
+        let mut map: RustyLevelDbMap<u64, u64> = RustyLevelDbMap::new(db.clone(), 1, "test map");
+        let mut other_map: RustyLevelDbMap<u64, u64> =
+            RustyLevelDbMap::new(db.clone(), 2, "other map");
+        let mut expected = HashMap::new();
+        for i in 0..20 {
+            map.insert(i, i + 100);
+            other_map.insert(i, 0);
+            expected.insert(i, i + 100);
+        }
+
+        let write_batch = WriteBatch::new();
+        map.pull_queue(&write_batch);
+        other_map.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        map.remove(&5);
+        expected.remove(&5);
+        map.insert(6, 0);
+        expected.insert(6, 0);
+        map.insert(50, 50);
+        expected.insert(50, 50);
+
+        let entries: HashMap<_, _> = map.iter().collect();
+        assert_eq!(expected, entries);
+        assert_eq!(expected.len() as u64, map.len());
+
+        let other_entries = other_map.iter().map(|(k, _)| k).sorted().collect_vec();
+        assert_eq!((0..20).collect_vec(), other_entries);
+    }
+
+    #[test]
+    fn iter_skips_foreign_keys_sharing_the_entry_prefix() {
+        let db = get_test_db();
+        let mut map: RustyLevelDbMap<u64, u64> = RustyLevelDbMap::new(db.clone(), 3, "test map");
+        map.insert(7, 49);
+
+        // keys of other data in the database, before, inside, and after the map's entry prefix
+        db.put_u8(&[2, 1, 0xff], &[0]).unwrap();
+        db.put_u8(&[3, 1, 0xff], &[0]).unwrap();
+        db.put_u8(&[4, 1, 0xff], &[0]).unwrap();
+
+        let entries = map.iter().collect_vec();
+        assert_eq!(vec![(7, 49)], entries);
+    }
+}
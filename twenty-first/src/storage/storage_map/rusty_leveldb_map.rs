@@ -0,0 +1,110 @@
+use super::super::level_db::DB;
+use super::rusty_leveldb_map_private::RustyLevelDbMapPrivate;
+use super::traits::*;
+use crate::sync::{AtomicRw, AtomicRwReadGuard, AtomicRwWriteGuard};
+use leveldb::batch::WriteBatch;
+use serde::{de::DeserializeOwned, Serialize};
+use std::hash::Hash;
+
+/// A concurrency safe database-backed HashMap with in memory write caching for all operations.
+#[derive(Debug, Clone)]
+pub struct RustyLevelDbMap<K, V> {
+    inner: AtomicRw<RustyLevelDbMapPrivate<K, V>>,
+}
+
+impl<K, V> StorageHashMap<K, V> for RustyLevelDbMap<K, V>
+where
+    K: Serialize + DeserializeOwned + Eq + Hash + Clone,
+    V: Serialize + DeserializeOwned + Clone,
+{
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.read_lock().is_empty()
+    }
+
+    #[inline]
+    fn len(&self) -> u64 {
+        self.read_lock().len()
+    }
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<V> {
+        self.read_lock().get(key)
+    }
+
+    #[inline]
+    fn contains_key(&self, key: &K) -> bool {
+        self.read_lock().contains_key(key)
+    }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.write_lock().insert(key, value)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.write_lock().remove(key)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (K, V)> + '_> {
+        // note: this lock is moved into the iterator closure and is not
+        //       released until caller drops the returned iterator
+        let inner = self.read_lock();
+        let keys = inner.keys();
+
+        Box::new(keys.into_iter().map(move |key| {
+            let value = inner.get(&key).expect("value for listed key should exist");
+            (key, value)
+        }))
+    }
+}
+
+impl<K, V> RustyLevelDbMap<K, V> {
+    #[inline]
+    pub(crate) fn write_lock(&mut self) -> AtomicRwWriteGuard<'_, RustyLevelDbMapPrivate<K, V>> {
+        self.inner.lock_guard_mut()
+    }
+
+    #[inline]
+    pub(crate) fn read_lock(&self) -> AtomicRwReadGuard<'_, RustyLevelDbMapPrivate<K, V>> {
+        self.inner.lock_guard()
+    }
+}
+
+impl<K, V> RustyLevelDbMap<K, V>
+where
+    K: Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    // Return the key used to store the length of the persisted map
+    #[inline]
+    pub fn get_length_key(key_prefix: u8) -> [u8; 2] {
+        RustyLevelDbMapPrivate::<K, V>::get_length_key(key_prefix)
+    }
+
+    /// Return the number of entries at the last write to disk
+    #[inline]
+    pub fn persisted_len(&self) -> u64 {
+        self.read_lock().persisted_len()
+    }
+
+    /// Return the level-DB key used to store the value associated with a key
+    #[inline]
+    pub fn get_entry_key(&self, key: &K) -> Vec<u8> {
+        self.read_lock().get_entry_key(key)
+    }
+
+    #[inline]
+    pub fn new(db: DB, key_prefix: u8, name: &str) -> Self {
+        Self {
+            inner: AtomicRw::from(RustyLevelDbMapPrivate::<K, V>::new(db, key_prefix, name)),
+        }
+    }
+
+    /// Collect all added or removed entries that have not yet been persisted
+    #[inline]
+    pub fn pull_queue(&mut self, write_batch: &WriteBatch) {
+        self.write_lock().pull_queue(write_batch)
+    }
+}
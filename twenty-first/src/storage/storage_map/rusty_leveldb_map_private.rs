@@ -0,0 +1,184 @@
+use super::super::level_db::DB;
+use super::super::utils;
+use leveldb::batch::WriteBatch;
+use leveldb::iterator::{Iterable, LevelDBIterator};
+use leveldb::options::ReadOptions;
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+const LENGTH_KEY: u8 = 0;
+const ENTRY_KEY: u8 = 1;
+
+/// This is the private impl of RustyLevelDbMap.
+///
+/// RustyLevelDbMap is a public wrapper that adds RwLock around
+/// all accesses to RustyLevelDbMapPrivate
+#[derive(Debug, Clone)]
+pub struct RustyLevelDbMapPrivate<K, V> {
+    key_prefix: u8,
+    db: DB,
+    write_queue: VecDeque<WriteElement<K, V>>,
+    length: u64,
+
+    /// The pending state of every key that has been written to since the
+    /// last call to `pull_queue`. `None` marks a removed key.
+    cache: HashMap<K, Option<V>>,
+    pub(super) name: String,
+}
+
+impl<K, V> RustyLevelDbMapPrivate<K, V>
+where
+    K: Serialize + DeserializeOwned + Eq + Hash + Clone,
+    V: Serialize + DeserializeOwned + Clone,
+{
+    #[inline]
+    pub(super) fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[inline]
+    pub(super) fn len(&self) -> u64 {
+        self.length
+    }
+
+    pub(super) fn get(&self, key: &K) -> Option<V> {
+        // try cache first
+        if let Some(maybe_value) = self.cache.get(key) {
+            return maybe_value.clone();
+        }
+
+        // then try persistent storage
+        let db_key = self.get_entry_key(key);
+        utils::get_u8_option(&self.db, &db_key, &self.name).map(|bytes| utils::deserialize(&bytes))
+    }
+
+    #[inline]
+    pub(super) fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub(super) fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let old_value = self.get(&key);
+        if old_value.is_none() {
+            self.length += 1;
+        }
+
+        self.cache.insert(key.clone(), Some(value.clone()));
+        self.write_queue
+            .push_back(WriteElement::Insert((key, value)));
+
+        old_value
+    }
+
+    pub(super) fn remove(&mut self, key: &K) -> Option<V> {
+        let old_value = self.get(key)?;
+        self.length -= 1;
+
+        self.cache.insert(key.clone(), None);
+        self.write_queue
+            .push_back(WriteElement::Remove(key.clone()));
+
+        Some(old_value)
+    }
+
+    /// All keys with an associated value, taking pending writes into account.
+    ///
+    /// Only the database keys starting with this map's entry prefix are visited. Keys in that
+    /// range that do not deserialize into a `K` were not written by this map and are skipped.
+    pub(super) fn keys(&self) -> Vec<K> {
+        let entry_key_prefix = vec![self.key_prefix, ENTRY_KEY];
+        let persisted_keys = self
+            .db
+            .keys_iter(&ReadOptions::new())
+            .from(&entry_key_prefix)
+            .take_while(|db_key| db_key.starts_with(&entry_key_prefix))
+            .filter_map(|db_key| utils::try_deserialize::<K>(&db_key[entry_key_prefix.len()..]))
+            .filter(|key| !self.cache.contains_key(key));
+
+        let pending_keys = self
+            .cache
+            .iter()
+            .filter(|(_, maybe_value)| maybe_value.is_some())
+            .map(|(key, _)| key.clone());
+
+        let keys: HashSet<_> = persisted_keys.chain(pending_keys).collect();
+        keys.into_iter().collect()
+    }
+}
+
+// ************ non-trait methods (StorageHashMap) **************/
+impl<K, V> RustyLevelDbMapPrivate<K, V>
+where
+    K: Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    // Return the key used to store the length of the persisted map
+    #[inline]
+    pub(crate) fn get_length_key(key_prefix: u8) -> [u8; 2] {
+        [key_prefix, LENGTH_KEY]
+    }
+
+    /// Return the length at the last write to disk
+    #[inline]
+    pub(crate) fn persisted_len(&self) -> u64 {
+        let key = Self::get_length_key(self.key_prefix);
+        match utils::get_u8_option(&self.db, &key, &self.name) {
+            Some(value) => utils::deserialize(&value),
+            None => 0,
+        }
+    }
+
+    /// Return the level-DB key used to store the value associated with a key
+    #[inline]
+    pub(crate) fn get_entry_key(&self, key: &K) -> Vec<u8> {
+        [vec![self.key_prefix, ENTRY_KEY], utils::serialize(key)].concat()
+    }
+
+    #[inline]
+    pub(crate) fn new(db: DB, key_prefix: u8, name: &str) -> Self {
+        let length_key = Self::get_length_key(key_prefix);
+        let length = match utils::get_u8_option(&db, &length_key, name) {
+            Some(length_bytes) => utils::deserialize(&length_bytes),
+            None => 0,
+        };
+        Self {
+            key_prefix,
+            db,
+            write_queue: VecDeque::default(),
+            length,
+            cache: HashMap::new(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Collect all added or removed entries that have not yet been persisted
+    pub(crate) fn pull_queue(&mut self, write_batch: &WriteBatch) {
+        while let Some(write_element) = self.write_queue.pop_front() {
+            match write_element {
+                WriteElement::Insert((k, v)) => {
+                    let key = self.get_entry_key(&k);
+                    let value = utils::serialize(&v);
+                    write_batch.put(&key, &value);
+                }
+                WriteElement::Remove(k) => {
+                    let key = self.get_entry_key(&k);
+                    write_batch.delete(&key);
+                }
+            };
+        }
+
+        if self.persisted_len() != self.length {
+            let key = Self::get_length_key(self.key_prefix);
+            write_batch.put_u8(&key, &utils::serialize(&self.length));
+        }
+
+        self.cache.clear();
+    }
+}
+
+#[derive(Debug, Clone)]
+enum WriteElement<K, V> {
+    Insert((K, V)),
+    Remove(K),
+}
@@ -0,0 +1,40 @@
+//! Traits that define the StorageHashMap interface
+//!
+//! It is recommended to wildcard import these with
+//! `use twenty_first::storage::storage_map::traits::*`
+
+pub trait StorageHashMap<K, V> {
+    /// check if collection is empty
+    fn is_empty(&self) -> bool;
+
+    /// get number of entries in the collection
+    fn len(&self) -> u64;
+
+    /// get the value associated with a key, if any
+    fn get(&self, key: &K) -> Option<V>;
+
+    /// check if the collection contains a value for a key
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// associate a value with a key, returning the previously associated
+    /// value, if any.
+    ///
+    /// note: The update is performed as a single atomic operation.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// remove the value associated with a key, returning it, if any.
+    ///
+    /// note: The update is performed as a single atomic operation.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// get an iterator over all entries, in no particular order
+    ///
+    /// The returned iterator holds a read-lock over the collection contents.
+    /// This enables consistent (snapshot) reads because any writer must
+    /// wait until the lock is released.
+    ///
+    /// The lock is not released until the iterator is dropped, so it is
+    /// important to drop the iterator immediately after use.  Typical
+    /// for-loop usage does this automatically.
+    fn iter(&self) -> Box<dyn Iterator<Item = (K, V)> + '_>;
+}
@@ -1,9 +1,10 @@
 use super::super::storage_vec::traits::*;
-use super::super::storage_vec::Index;
+use super::super::storage_vec::{Index, StorageVecSnapshot};
 use super::dbtvec_private::DbtVecPrivate;
 use super::{traits::*, RustyValue, VecWriteOperation, WriteOperation};
 use crate::sync::{AtomicRw, AtomicRwReadGuard, AtomicRwWriteGuard, LockCallbackFn};
 use serde::{de::DeserializeOwned, Serialize};
+use std::ops::RangeBounds;
 use std::{fmt::Debug, sync::Arc};
 
 /// A DB-backed Vec for use with DBSchema
@@ -99,6 +100,18 @@ where
         self.inner.lock(|inner| inner.get(index))
     }
 
+    #[inline]
+    fn first(&self) -> Option<V> {
+        self.inner
+            .lock(|inner| (!inner.is_empty()).then(|| inner.get(0)))
+    }
+
+    #[inline]
+    fn last(&self) -> Option<V> {
+        self.inner
+            .lock(|inner| (!inner.is_empty()).then(|| inner.get(inner.len() - 1)))
+    }
+
     #[inline]
     fn many_iter<'a>(
         &'a self,
@@ -169,6 +182,16 @@ where
         self.inner.lock_mut(|inner| inner.set_many(key_vals));
     }
 
+    #[inline]
+    fn swap(&mut self, a: Index, b: Index) {
+        self.inner.lock_mut(|inner| inner.swap(a, b));
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: Index) -> V {
+        self.inner.lock_mut(|inner| inner.swap_remove(index))
+    }
+
     #[inline]
     fn pop(&mut self) -> Option<V> {
         self.inner.lock_mut(|inner| inner.pop())
@@ -179,10 +202,48 @@ where
         self.inner.lock_mut(|inner| inner.push(value));
     }
 
+    #[inline]
+    fn extend(&mut self, values: impl IntoIterator<Item = V>) {
+        self.inner.lock_mut(|inner| inner.extend(values));
+    }
+
+    #[inline]
+    fn pop_many(&mut self, count: usize) -> Vec<V> {
+        self.inner.lock_mut(|inner| inner.pop_many(count))
+    }
+
+    #[inline]
+    fn retain<F: FnMut(&V) -> bool>(&mut self, f: F) {
+        self.inner.lock_mut(|inner| inner.retain(f));
+    }
+
     #[inline]
     fn clear(&mut self) {
         self.inner.lock_mut(|inner| inner.clear());
     }
+
+    #[inline]
+    fn truncate(&mut self, len: Index) {
+        self.inner.lock_mut(|inner| inner.truncate(len));
+    }
+
+    #[inline]
+    fn resize(&mut self, new_len: Index, value: V) {
+        self.inner.lock_mut(|inner| inner.resize(new_len, value));
+    }
+
+    #[inline]
+    fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<V> {
+        self.inner.lock_mut(|inner| inner.drain(range))
+    }
+
+    #[inline]
+    fn restore_from_snapshot(&mut self, snapshot: StorageVecSnapshot<V>) {
+        self.inner.lock_mut(|inner| {
+            inner.clear();
+            inner.extend(snapshot.elements);
+        });
+    }
 }
 
 impl<V> DbTable for DbtVec<V>
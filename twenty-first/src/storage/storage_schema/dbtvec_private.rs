@@ -1,10 +1,12 @@
 use super::super::storage_vec::traits::*;
 use super::super::storage_vec::Index;
+use super::super::utils;
 use super::RustyKey;
 use super::{traits::StorageReader, VecWriteOperation};
 use itertools::Itertools;
 use serde::de::DeserializeOwned;
 use std::fmt::{Debug, Formatter};
+use std::ops::RangeBounds;
 use std::{
     collections::{HashMap, VecDeque},
     sync::Arc,
@@ -302,6 +304,31 @@ where
         }
     }
 
+    pub(super) fn swap(&mut self, a: Index, b: Index) {
+        let value_a = self.get(a);
+        let value_b = self.get(b);
+        self.set(a, value_b);
+        self.set(b, value_a);
+    }
+
+    pub(super) fn swap_remove(&mut self, index: Index) -> V {
+        assert!(
+            index < self.len(),
+            "Out-of-bounds. Got {index} but length was {}. persisted vector name: {}",
+            self.len(),
+            self.name
+        );
+
+        let last = self.pop().expect("vector should be non-empty");
+        if index == self.len() {
+            return last;
+        }
+
+        let removed = self.get(index);
+        self.set(index, last);
+        removed
+    }
+
     #[inline]
     pub(super) fn pop(&mut self) -> Option<V> {
         // If vector is empty, return None
@@ -347,10 +374,93 @@ where
         self.current_length = Some(current_length + 1);
     }
 
+    pub(super) fn extend(&mut self, values: impl IntoIterator<Item = V>) {
+        let mut length = self.len();
+        for value in values {
+            self.write_queue
+                .push_back(VecWriteOperation::Push(value.clone()));
+            self.cache.insert(length, value);
+            length += 1;
+        }
+
+        self.current_length = Some(length);
+    }
+
     #[inline]
     pub(super) fn clear(&mut self) {
         while !self.is_empty() {
             self.pop();
         }
     }
+
+    pub(super) fn truncate(&mut self, len: Index) {
+        let current_length = self.len();
+        if len >= current_length {
+            return;
+        }
+
+        // Pending overwrites of dropped elements would be deleted again
+        // when the queue is persisted, so there is no point in writing them.
+        self.write_queue.retain(|op| match op {
+            VecWriteOperation::OverWrite((i, _)) => *i < len,
+            _ => true,
+        });
+
+        // Unlike `pop`, don't read the dropped elements. Each `Pop` deletes
+        // the key of the then-last element when the queue is persisted.
+        for index in (len..current_length).rev() {
+            self.cache.remove(&index);
+            self.write_queue.push_back(VecWriteOperation::Pop);
+        }
+
+        self.current_length = Some(len);
+    }
+
+    pub(super) fn resize(&mut self, new_len: Index, value: V) {
+        let current_length = self.len();
+        if new_len <= current_length {
+            self.truncate(new_len);
+            return;
+        }
+
+        for _ in current_length..new_len {
+            self.push(value.clone());
+        }
+    }
+
+    pub(super) fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<V> {
+        let range = utils::resolve_range(range, self.len());
+        let drained = self.get_many(&range.clone().collect_vec());
+
+        // shift the elements after the range down to close the gap
+        let tail = self.get_many(&(range.end..self.len()).collect_vec());
+        let new_len = range.start + tail.len() as Index;
+        self.set_many((range.start..).zip(tail));
+        self.truncate(new_len);
+
+        drained.into_iter()
+    }
+
+    pub(super) fn pop_many(&mut self, count: usize) -> Vec<V> {
+        let start = self.len().saturating_sub(count as Index);
+        self.drain(start..).rev().collect()
+    }
+
+    /// Only elements that change their index are written.
+    pub(super) fn retain(&mut self, mut f: impl FnMut(&V) -> bool) {
+        let mut num_retained = 0;
+        let mut moved_elements = vec![];
+        for (index, element) in (0..).zip(self.get_all()) {
+            if !f(&element) {
+                continue;
+            }
+            if index != num_retained {
+                moved_elements.push((num_retained, element));
+            }
+            num_retained += 1;
+        }
+
+        self.set_many(moved_elements);
+        self.truncate(num_retained);
+    }
 }
@@ -644,6 +644,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extend_vector() {
+        let db = DB::open_new_test_database(true, None, None, None).unwrap();
+        let mut rusty_storage = SimpleRustyStorage::new(db);
+        let mut vector = rusty_storage.schema.new_vec::<u64>("test-vector");
+
+        // initialize
+        rusty_storage.restore_or_new();
+        vector.push(1776);
+
+        let mut regular_vec = vec![1776];
+        let new_values: Vec<u64> = random_elements(10_000);
+        vector.extend(new_values.clone());
+        regular_vec.extend(new_values);
+        assert_eq!(regular_vec.len() as Index, vector.len());
+        assert_eq!(regular_vec, vector.get_all());
+
+        rusty_storage.persist();
+        assert_eq!(regular_vec, vector.get_all());
+    }
+
+    #[test]
+    fn swap_remove_from_vector() {
+        let db = DB::open_new_test_database(true, None, None, None).unwrap();
+        let mut rusty_storage = SimpleRustyStorage::new(db);
+        let mut vector = rusty_storage.schema.new_vec::<u64>("test-vector");
+
+        // initialize
+        rusty_storage.restore_or_new();
+        let mut regular_vec: Vec<u64> = random_elements(100);
+        vector.extend(regular_vec.clone());
+        rusty_storage.persist();
+
+        assert_eq!(regular_vec.swap_remove(42), vector.swap_remove(42));
+        vector.swap(3, 17);
+        regular_vec.swap(3, 17);
+        assert_eq!(regular_vec, vector.get_all());
+
+        rusty_storage.persist();
+        assert_eq!(regular_vec, vector.get_all());
+    }
+
+    #[test]
+    fn truncate_and_resize_vector() {
+        let db = DB::open_new_test_database(false, None, None, None).unwrap();
+        let db_path = db.path().clone();
+        let mut rusty_storage = SimpleRustyStorage::new(db);
+        let mut vector = rusty_storage.schema.new_vec::<u64>("test-vector");
+
+        // initialize
+        rusty_storage.restore_or_new();
+        for i in 0..10 {
+            vector.push(i);
+        }
+        rusty_storage.persist();
+
+        vector.set(8, 1776);
+        vector.truncate(4);
+        assert_eq!(vec![0, 1, 2, 3], vector.get_all());
+
+        vector.truncate(7);
+        assert_eq!(vec![0, 1, 2, 3], vector.get_all());
+
+        vector.resize(6, 42);
+        assert_eq!(vec![0, 1, 2, 3, 42, 42], vector.get_all());
+
+        vector.resize(2, 42);
+        assert_eq!(vec![0, 1], vector.get_all());
+        vector.resize(3, 17);
+        rusty_storage.persist();
+
+        drop(rusty_storage); // <-- DB ref dropped
+        drop(vector); //        <-- final DB ref dropped (DB closes)
+
+        // re-open DB / restore from disk
+        let new_db = DB::open_test_database(&db_path, true, None, None, None).unwrap();
+        let mut new_rusty_storage = SimpleRustyStorage::new(new_db);
+        let new_vector = new_rusty_storage.schema.new_vec::<u64>("test-vector");
+        new_rusty_storage.restore_or_new();
+        assert_eq!(vec![0, 1, 17], new_vector.get_all());
+    }
+
+    #[test]
+    fn first_and_last_of_vector() {
+        let db = DB::open_new_test_database(true, None, None, None).unwrap();
+        let mut rusty_storage = SimpleRustyStorage::new(db);
+        let mut vector = rusty_storage.schema.new_vec::<u64>("test-vector");
+
+        // initialize
+        rusty_storage.restore_or_new();
+        assert_eq!(None, vector.first());
+        assert_eq!(None, vector.last());
+
+        vector.extend([1, 2, 3]);
+        rusty_storage.persist();
+        assert_eq!(Some(1), vector.first());
+        assert_eq!(Some(3), vector.last());
+
+        vector.pop();
+        vector.pop();
+        assert_eq!(Some(1), vector.first());
+        assert_eq!(Some(1), vector.last());
+
+        vector.pop();
+        assert_eq!(None, vector.first());
+        assert_eq!(None, vector.last());
+    }
+
+    #[test]
+    fn drain_vector() {
+        let db = DB::open_new_test_database(false, None, None, None).unwrap();
+        let db_path = db.path().clone();
+        let mut rusty_storage = SimpleRustyStorage::new(db);
+        let mut vector = rusty_storage.schema.new_vec::<u64>("test-vector");
+
+        // initialize
+        rusty_storage.restore_or_new();
+        for i in 0..10 {
+            vector.push(i);
+        }
+        rusty_storage.persist();
+
+        vector.set(6, 1776);
+        let drained = vector.drain(2..5).collect_vec();
+        assert_eq!(vec![2, 3, 4], drained);
+        assert_eq!(vec![0, 1, 5, 1776, 7, 8, 9], vector.get_all());
+        rusty_storage.persist();
+
+        drop(rusty_storage); // <-- DB ref dropped
+        drop(vector); //        <-- final DB ref dropped (DB closes)
+
+        // re-open DB / restore from disk
+        let new_db = DB::open_test_database(&db_path, true, None, None, None).unwrap();
+        let mut new_rusty_storage = SimpleRustyStorage::new(new_db);
+        let new_vector = new_rusty_storage.schema.new_vec::<u64>("test-vector");
+        new_rusty_storage.restore_or_new();
+        assert_eq!(vec![0, 1, 5, 1776, 7, 8, 9], new_vector.get_all());
+    }
+
     #[test]
     fn singleton_vector_key_collission() {
         let db = DB::open_new_test_database(false, None, None, None).unwrap();
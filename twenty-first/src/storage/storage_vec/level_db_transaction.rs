@@ -0,0 +1,184 @@
+use super::super::level_db::DB;
+use super::rusty_leveldb_vec_private::RustyLevelDbVecPrivate;
+use super::RustyLevelDbVec;
+use crate::sync::AtomicRwWriteGuard;
+use leveldb::batch::WriteBatch;
+use leveldb::error::Error as DbError;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Persists the pending changes of several [`RustyLevelDbVec`]s sharing one
+/// [`DB`] in a single, atomic [`WriteBatch`].
+///
+/// Either the changes of all registered vectors are written, or none are. If
+/// writing fails, the vectors keep their pending changes, and the commit can be
+/// retried.
+///
+/// The write locks of all registered vectors are held for the duration of the
+/// commit, in order of registration. To avoid deadlocks, concurrent
+/// transactions over the same vectors must register them in the same order.
+///
+/// Every vector may be registered at most once. Since clones of a
+/// [`RustyLevelDbVec`] share their state and lock, registering two clones of the
+/// same vector counts as registering it twice. Committing such a transaction
+/// deadlocks on the second write lock.
+///
+/// # Example:
+/// ```
+/// # use twenty_first::storage::level_db::DB;
+/// # use twenty_first::storage::storage_vec::{LevelDbTransaction, RustyLevelDbVec, traits::*};
+/// # let db = DB::open_new_test_database(true, None, None, None).unwrap();
+/// let mut blocks = RustyLevelDbVec::<u64>::new(db.clone(), 0, "blocks");
+/// let mut balances = RustyLevelDbVec::<u64>::new(db.clone(), 1, "balances");
+/// blocks.push(1);
+/// balances.push(100);
+///
+/// let mut transaction = LevelDbTransaction::new(db);
+/// transaction.register(&mut blocks);
+/// transaction.register(&mut balances);
+/// transaction.commit().unwrap();
+///
+/// assert_eq!(1, blocks.persisted_length());
+/// assert_eq!(1, balances.persisted_length());
+/// ```
+pub struct LevelDbTransaction<'a> {
+    db: DB,
+    vectors: Vec<&'a mut dyn LockWriteQueue>,
+}
+
+impl<'a> LevelDbTransaction<'a> {
+    pub fn new(db: DB) -> Self {
+        Self {
+            db,
+            vectors: vec![],
+        }
+    }
+
+    /// Include the pending changes of the given vector in the commit. The vector must
+    /// be stored in the transaction's database, and neither it nor any of its clones
+    /// may already be registered.
+    pub fn register<T>(&mut self, vector: &'a mut RustyLevelDbVec<T>)
+    where
+        T: Serialize + DeserializeOwned + Clone + 'a,
+    {
+        self.vectors.push(vector);
+    }
+
+    /// Write the pending changes of all registered vectors in one batch.
+    pub fn commit(self) -> Result<(), DbError> {
+        let mut db = self.db.clone();
+        self.commit_with(|write_batch| db.write_auto(write_batch))
+    }
+
+    fn commit_with(
+        mut self,
+        write: impl FnOnce(&WriteBatch) -> Result<(), DbError>,
+    ) -> Result<(), DbError> {
+        // Hold all locks until the end, so no vector can change between collecting its
+        // pending changes and dropping them from its queue.
+        let mut locked_queues = self
+            .vectors
+            .iter_mut()
+            .map(|vector| vector.lock_write_queue())
+            .collect::<Vec<_>>();
+
+        let write_batch = WriteBatch::new();
+        for queue in &locked_queues {
+            queue.queue_to_batch(&write_batch);
+        }
+
+        write(&write_batch)?;
+
+        for queue in &mut locked_queues {
+            queue.clear_queue();
+        }
+        Ok(())
+    }
+}
+
+/// The write queue of a locked vector.
+trait WriteQueue {
+    fn queue_to_batch(&self, write_batch: &WriteBatch);
+
+    fn clear_queue(&mut self);
+}
+
+impl<T> WriteQueue for AtomicRwWriteGuard<'_, RustyLevelDbVecPrivate<T>>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    fn queue_to_batch(&self, write_batch: &WriteBatch) {
+        RustyLevelDbVecPrivate::queue_to_batch(self, write_batch)
+    }
+
+    fn clear_queue(&mut self) {
+        RustyLevelDbVecPrivate::clear_queue(self)
+    }
+}
+
+/// A vector whose write queue can be locked for a transaction.
+trait LockWriteQueue {
+    fn lock_write_queue(&mut self) -> Box<dyn WriteQueue + '_>;
+}
+
+impl<T> LockWriteQueue for RustyLevelDbVec<T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    fn lock_write_queue(&mut self) -> Box<dyn WriteQueue + '_> {
+        Box::new(self.write_lock())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::get_test_db;
+    use super::super::traits::*;
+    use super::*;
+
+    #[test]
+    fn vectors_committed_together_are_either_both_updated_or_both_unchanged() {
+        let db = get_test_db(true);
+        let mut vec_a = RustyLevelDbVec::<u64>::new(db.clone(), 0, "vec a");
+        let mut vec_b = RustyLevelDbVec::<u64>::new(db.clone(), 1, "vec b");
+        vec_a.extend([1, 2, 3]);
+        vec_b.extend([10, 20]);
+
+        let mut transaction = LevelDbTransaction::new(db.clone());
+        transaction.register(&mut vec_a);
+        transaction.register(&mut vec_b);
+        transaction.commit().unwrap();
+
+        vec_a.push(4);
+        vec_a.set(0, 100);
+        vec_b.pop();
+
+        // simulate a failure after the batch has been assembled, but before it is written
+        let mut transaction = LevelDbTransaction::new(db.clone());
+        transaction.register(&mut vec_a);
+        transaction.register(&mut vec_b);
+        let simulated_failure = DbError::new("simulated failure".to_string());
+        let result = transaction.commit_with(|_| Err(simulated_failure));
+        assert!(result.is_err());
+
+        let reloaded_a = RustyLevelDbVec::<u64>::new(db.clone(), 0, "vec a");
+        let reloaded_b = RustyLevelDbVec::<u64>::new(db.clone(), 1, "vec b");
+        assert_eq!(vec![1, 2, 3], reloaded_a.get_all());
+        assert_eq!(vec![10, 20], reloaded_b.get_all());
+
+        // pending changes survive the failed commit
+        assert_eq!(vec![100, 2, 3, 4], vec_a.get_all());
+        assert_eq!(vec![10], vec_b.get_all());
+
+        let mut transaction = LevelDbTransaction::new(db.clone());
+        transaction.register(&mut vec_a);
+        transaction.register(&mut vec_b);
+        transaction.commit().unwrap();
+
+        let reloaded_a = RustyLevelDbVec::<u64>::new(db.clone(), 0, "vec a");
+        let reloaded_b = RustyLevelDbVec::<u64>::new(db, 1, "vec b");
+        assert_eq!(vec![100, 2, 3, 4], reloaded_a.get_all());
+        assert_eq!(vec![10], reloaded_b.get_all());
+        assert_eq!(4, vec_a.persisted_length());
+        assert_eq!(1, vec_b.persisted_length());
+    }
+}
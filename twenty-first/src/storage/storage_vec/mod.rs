@@ -8,15 +8,30 @@
 
 #![allow(missing_docs)]
 mod iterators;
+mod level_db_transaction;
 mod ordinary_vec;
 mod ordinary_vec_private;
+mod persisted_vec;
+mod persisted_vec_private;
+mod ring_buffer;
 mod rusty_leveldb_vec;
 mod rusty_leveldb_vec_private;
+#[cfg(feature = "sled")]
+mod sled_vec;
+#[cfg(feature = "sled")]
+mod sled_vec_private;
+mod snapshot;
 pub mod traits;
 
 pub type Index = u64;
 
-pub use {iterators::*, ordinary_vec::*, rusty_leveldb_vec::*};
+pub use {
+    iterators::*, level_db_transaction::*, ordinary_vec::*, persisted_vec::*, ring_buffer::*,
+    rusty_leveldb_vec::*, snapshot::*,
+};
+
+#[cfg(feature = "sled")]
+pub use sled_vec::*;
 
 #[cfg(test)]
 mod tests {
@@ -517,4 +532,426 @@ mod tests {
         delegated_db_vec.pop();
         delegated_db_vec.set(11, 5000);
     }
+
+    #[should_panic(
+        expected = "Out-of-bounds. Got 5 but length was 5. persisted vector name: unit test vec 0"
+    )]
+    #[test]
+    fn panic_on_get_of_truncated_index() {
+        let (mut delegated_db_vec, _, _) = get_persisted_vec_with_length(12, "unit test vec 0");
+        delegated_db_vec.truncate(5);
+        delegated_db_vec.get(5);
+    }
+
+    #[test]
+    fn truncate_with_larger_length_has_no_effect() {
+        let (mut delegated_db_vec, regular_vec, _) =
+            get_persisted_vec_with_length(12, "unit test vec 0");
+        delegated_db_vec.truncate(12);
+        delegated_db_vec.truncate(20);
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+    }
+
+    #[test]
+    fn truncate_and_resize_are_persisted() {
+        let (mut delegated_db_vec, mut regular_vec, mut db) =
+            get_persisted_vec_with_length(12, "unit test vec 0");
+
+        delegated_db_vec.set(10, 42);
+        delegated_db_vec.set(3, 43);
+        regular_vec[3] = 43;
+        delegated_db_vec.truncate(5);
+        regular_vec.truncate(5);
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+
+        delegated_db_vec.resize(8, 1337);
+        regular_vec.resize(8, 1337);
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        delegated_db_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(8, reloaded_vec.persisted_length());
+        assert_eq!(regular_vec, reloaded_vec.get_all());
+    }
+
+    #[test]
+    fn drain_yields_range_and_reindexes_remaining_elements() {
+        let (mut delegated_db_vec, mut regular_vec, mut db) =
+            get_persisted_vec_with_length(10, "unit test vec 0");
+
+        delegated_db_vec.set(7, 42);
+        regular_vec[7] = 42;
+        let drained = delegated_db_vec.drain(2..5).collect_vec();
+        let expected = regular_vec.drain(2..5).collect_vec();
+        assert_eq!(expected, drained);
+        assert_eq!(7, delegated_db_vec.len());
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        delegated_db_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(7, reloaded_vec.persisted_length());
+        assert_eq!(regular_vec, reloaded_vec.get_all());
+    }
+
+    #[test]
+    fn drain_agrees_with_vec_for_different_kinds_of_ranges() {
+        let (mut delegated_db_vec, mut regular_vec, _) =
+            get_persisted_vec_with_length(20, "unit test vec 0");
+        let mut ordinary_vec = OrdinaryVec::from(regular_vec.clone());
+
+        let expected = regular_vec.drain(15..).collect_vec();
+        assert_eq!(expected, delegated_db_vec.drain(15..).collect_vec());
+        assert_eq!(expected, ordinary_vec.drain(15..).collect_vec());
+
+        let expected = regular_vec.drain(..3).collect_vec();
+        assert_eq!(expected, delegated_db_vec.drain(..3).collect_vec());
+        assert_eq!(expected, ordinary_vec.drain(..3).collect_vec());
+
+        let expected = regular_vec.drain(4..=6).collect_vec();
+        assert_eq!(expected, delegated_db_vec.drain(4..=6).collect_vec());
+        assert_eq!(expected, ordinary_vec.drain(4..=6).collect_vec());
+
+        assert_eq!(0, delegated_db_vec.drain(5..5).count());
+        assert_eq!(0, ordinary_vec.drain(5..5).count());
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+        assert_eq!(regular_vec, ordinary_vec.get_all());
+
+        let expected = regular_vec.drain(..).collect_vec();
+        assert_eq!(expected, delegated_db_vec.drain(..).collect_vec());
+        assert_eq!(expected, ordinary_vec.drain(..).collect_vec());
+        assert!(delegated_db_vec.is_empty());
+        assert!(ordinary_vec.is_empty());
+    }
+
+    #[test]
+    fn pop_many_yields_values_from_push_many_in_lifo_order() {
+        let (mut delegated_db_vec, regular_vec, mut db) =
+            get_persisted_vec_with_length(5, "unit test vec 0");
+        let mut ordinary_vec = OrdinaryVec::from(regular_vec.clone());
+
+        let values = (100..110).collect_vec();
+        delegated_db_vec.push_many(values.clone());
+        ordinary_vec.push_many(values.clone());
+        assert_eq!(15, delegated_db_vec.len());
+        assert_eq!(15, ordinary_vec.len());
+
+        let expected = values.iter().rev().copied().collect_vec();
+        assert_eq!(expected, delegated_db_vec.pop_many(10));
+        assert_eq!(expected, ordinary_vec.pop_many(10));
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+        assert_eq!(regular_vec, ordinary_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        delegated_db_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(5, reloaded_vec.persisted_length());
+        assert_eq!(regular_vec, reloaded_vec.get_all());
+    }
+
+    #[test]
+    fn pop_many_with_count_exceeding_length_empties_vec() {
+        let (mut delegated_db_vec, regular_vec, _) =
+            get_persisted_vec_with_length(5, "unit test vec 0");
+        let mut ordinary_vec = OrdinaryVec::from(regular_vec.clone());
+
+        let expected = regular_vec.into_iter().rev().collect_vec();
+        assert_eq!(expected, delegated_db_vec.pop_many(8));
+        assert_eq!(expected, ordinary_vec.pop_many(8));
+        assert!(delegated_db_vec.is_empty());
+        assert!(ordinary_vec.is_empty());
+        assert!(delegated_db_vec.pop_many(3).is_empty());
+    }
+
+    #[test]
+    fn retain_keeps_matching_elements_in_order() {
+        let mut db = get_test_db(true);
+        let mut persisted_vec: RustyLevelDbVec<u64> =
+            RustyLevelDbVec::new(db.clone(), 0, "unit test vec 0");
+        let mut ordinary_vec: OrdinaryVec<u64> = OrdinaryVec::from(vec![]);
+        persisted_vec.extend(0..100);
+        ordinary_vec.extend(0..100);
+
+        let write_batch = WriteBatch::new();
+        persisted_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        persisted_vec.retain(|x| x % 2 == 0);
+        ordinary_vec.retain(|x| x % 2 == 0);
+        let expected = (0..100).step_by(2).collect_vec();
+        assert_eq!(50, persisted_vec.len());
+        assert_eq!(expected, persisted_vec.get_all());
+        assert_eq!(expected, ordinary_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        persisted_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(50, reloaded_vec.persisted_length());
+        assert_eq!(expected, reloaded_vec.get_all());
+    }
+
+    #[should_panic(expected = "Out-of-bounds. Got range end 11 but length was 10")]
+    #[test]
+    fn panic_on_out_of_bounds_drain() {
+        let (mut delegated_db_vec, _, _) = get_persisted_vec_with_length(10, "unit test vec 0");
+        delegated_db_vec.drain(8..11);
+    }
+
+    #[test]
+    fn contains_and_position_find_present_elements() {
+        let (persisted_vec, regular_vec, _) = get_persisted_vec_with_length(10, "unit test vec 0");
+        let ordinary_vec = OrdinaryVec::from(regular_vec.clone());
+
+        for (index, value) in regular_vec.iter().enumerate() {
+            assert!(persisted_vec.contains(value));
+            assert!(ordinary_vec.contains(value));
+
+            let expected = regular_vec
+                .iter()
+                .position(|v| v == value)
+                .map(|i| i as Index);
+            assert_eq!(Some(index as Index), expected);
+            assert_eq!(expected, persisted_vec.position(|v| v == value));
+            assert_eq!(expected, ordinary_vec.position(|v| v == value));
+        }
+    }
+
+    #[test]
+    fn contains_and_position_do_not_find_absent_elements() {
+        let db = get_test_db(true);
+        let mut persisted_vec: RustyLevelDbVec<u64> =
+            RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert!(!persisted_vec.contains(&0));
+        assert_eq!(None, persisted_vec.position(|_| true));
+
+        persisted_vec.extend((0..20).map(|i| 2 * i));
+        assert!(!persisted_vec.contains(&7));
+        assert!(!persisted_vec.contains(&40));
+        assert_eq!(None, persisted_vec.position(|v| v % 2 == 1));
+        assert_eq!(Some(3), persisted_vec.position(|&v| v > 5));
+    }
+
+    #[test]
+    fn binary_search_by_agrees_with_vec() {
+        let db = get_test_db(true);
+        let mut persisted_vec: RustyLevelDbVec<u64> =
+            RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        let regular_vec = (0..100).map(|i| 3 * i).collect_vec();
+        persisted_vec.extend(regular_vec.clone());
+
+        for needle in 0..310 {
+            let expected = regular_vec.binary_search(&needle);
+            let expected = expected.map(|i| i as Index).map_err(|i| i as Index);
+            let found = persisted_vec.binary_search_by(|element| element.cmp(&needle));
+            assert_eq!(expected, found);
+        }
+    }
+
+    #[test]
+    fn binary_search_by_in_empty_vec_gives_insertion_point_zero() {
+        let ordinary_vec = OrdinaryVec::<u64>::from(vec![]);
+        assert_eq!(
+            Err(0),
+            ordinary_vec.binary_search_by(|element| element.cmp(&42))
+        );
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_json() {
+        let (persisted_vec, regular_vec, _) =
+            get_persisted_vec_with_length(1000, "unit test vec 0");
+        assert_eq!(regular_vec, persisted_vec.to_vec());
+
+        let snapshot = persisted_vec.snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let deserialized_snapshot: StorageVecSnapshot<u64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot, deserialized_snapshot);
+
+        let (mut other_vec, _, mut db) = get_persisted_vec_with_length(20, "unit test vec 1");
+        other_vec.restore_from_snapshot(deserialized_snapshot);
+        assert_eq!(regular_vec, other_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        other_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 1");
+        assert_eq!(1000, reloaded_vec.persisted_length());
+        assert_eq!(regular_vec, reloaded_vec.get_all());
+    }
+
+    #[test]
+    fn first_and_last_agree_with_vec() {
+        let (mut persisted_vec, mut regular_vec, _) =
+            get_persisted_vec_with_length(3, "unit test vec 0");
+        let mut ordinary_vec = OrdinaryVec::from(regular_vec.clone());
+
+        while !regular_vec.is_empty() {
+            assert_eq!(regular_vec.first().copied(), persisted_vec.first());
+            assert_eq!(regular_vec.last().copied(), persisted_vec.last());
+            assert_eq!(regular_vec.first().copied(), ordinary_vec.first());
+            assert_eq!(regular_vec.last().copied(), ordinary_vec.last());
+
+            regular_vec.pop();
+            persisted_vec.pop();
+            ordinary_vec.pop();
+        }
+
+        assert_eq!(None, persisted_vec.first());
+        assert_eq!(None, persisted_vec.last());
+        assert_eq!(None, ordinary_vec.first());
+        assert_eq!(None, ordinary_vec.last());
+    }
+
+    #[test]
+    fn extend_agrees_with_vec() {
+        let (mut delegated_db_vec, mut regular_vec, mut db) =
+            get_persisted_vec_with_length(5, "unit test vec 0");
+
+        let new_values: Vec<u64> = (0..10_000).map(|_| rand::thread_rng().next_u64()).collect();
+        delegated_db_vec.extend(new_values.clone());
+        regular_vec.extend(new_values);
+        assert_eq!(regular_vec.len() as Index, delegated_db_vec.len());
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        delegated_db_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+        assert_eq!(
+            regular_vec.len() as Index,
+            delegated_db_vec.persisted_length()
+        );
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+    }
+
+    #[test]
+    fn iter_mut_writes_are_persisted() {
+        let (mut delegated_db_vec, regular_vec, mut db) =
+            get_persisted_vec_with_length(20, "unit test vec 0");
+
+        {
+            let mut iter = delegated_db_vec.many_iter_mut([2, 4, 8]);
+            while let Some(mut setter) = iter.next() {
+                let value = *setter.value();
+                setter.set(value.wrapping_add(1));
+            }
+        }
+
+        let expected = regular_vec
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| match i {
+                2 | 4 | 8 => v.wrapping_add(1),
+                _ => v,
+            })
+            .collect_vec();
+        assert_eq!(expected, delegated_db_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        delegated_db_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(expected, reloaded_vec.get_all());
+    }
+
+    #[test]
+    fn swap_exchanges_two_elements() {
+        let (mut delegated_db_vec, mut regular_vec, _) =
+            get_persisted_vec_with_length(10, "unit test vec 0");
+        delegated_db_vec.swap(2, 7);
+        regular_vec.swap(2, 7);
+        delegated_db_vec.swap(4, 4);
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+    }
+
+    #[test]
+    fn swap_remove_from_the_middle_preserves_remaining_elements() {
+        let (mut delegated_db_vec, mut regular_vec, mut db) =
+            get_persisted_vec_with_length(100, "unit test vec 0");
+
+        let expected_removed = regular_vec.swap_remove(42);
+        let removed = delegated_db_vec.swap_remove(42);
+        assert_eq!(expected_removed, removed);
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+
+        let write_batch = WriteBatch::new();
+        delegated_db_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(99, reloaded_vec.persisted_length());
+        assert_eq!(regular_vec, reloaded_vec.get_all());
+    }
+
+    #[test]
+    fn swap_remove_of_last_element_is_pop() {
+        let (mut delegated_db_vec, mut regular_vec, _) =
+            get_persisted_vec_with_length(10, "unit test vec 0");
+        let expected_removed = regular_vec.pop().unwrap();
+        assert_eq!(expected_removed, delegated_db_vec.swap_remove(9));
+        assert_eq!(regular_vec, delegated_db_vec.get_all());
+    }
+
+    #[should_panic(
+        expected = "Out-of-bounds. Got 0 but length was 0. persisted vector name: unit test vec 0"
+    )]
+    #[test]
+    fn panic_on_swap_remove_from_empty_vec() {
+        let (mut delegated_db_vec, _, _) = get_persisted_vec_with_length(0, "unit test vec 0");
+        delegated_db_vec.swap_remove(0);
+    }
+
+    #[test]
+    fn reads_beyond_read_cache_capacity_return_correct_values() {
+        let (persisted_vec, regular_vec, db) = get_persisted_vec_with_length(50, "unit test vec 0");
+        drop(persisted_vec);
+
+        let mut cached_vec: RustyLevelDbVec<u64> =
+            RustyLevelDbVec::with_cache_capacity(db.clone(), 0, "unit test vec 0", 8);
+        for _ in 0..3 {
+            for (i, &value) in regular_vec.iter().enumerate() {
+                assert_eq!(value, cached_vec.get(i as Index));
+            }
+        }
+        assert_eq!(regular_vec, cached_vec.get_all());
+
+        // overwrite an element that is currently in the read cache
+        cached_vec.set(49, 1776);
+        assert_eq!(1776, cached_vec.get(49));
+        cached_vec.pop();
+        assert_eq!(49, cached_vec.len());
+        assert_eq!(50, cached_vec.persisted_length());
+        assert_eq!(regular_vec[..49].to_vec(), cached_vec.get_all());
+    }
+
+    #[test]
+    fn dirty_elements_are_not_evicted_from_capacity_limited_cache() {
+        let mut db = get_test_db(true);
+        let mut cached_vec: RustyLevelDbVec<u64> =
+            RustyLevelDbVec::with_cache_capacity(db.clone(), 0, "unit test vec 0", 4);
+
+        let values = (0..100).collect_vec();
+        cached_vec.extend(values.clone());
+        for i in 0..100 {
+            assert_eq!(values[i as usize], cached_vec.get(i));
+        }
+
+        let write_batch = WriteBatch::new();
+        cached_vec.pull_queue(&write_batch);
+        assert!(db.write_auto(&write_batch).is_ok());
+        assert_eq!(100, cached_vec.persisted_length());
+
+        let reloaded_vec: RustyLevelDbVec<u64> = RustyLevelDbVec::new(db, 0, "unit test vec 0");
+        assert_eq!(values, reloaded_vec.get_all());
+    }
 }
@@ -1,6 +1,7 @@
 use super::ordinary_vec_private::OrdinaryVecPrivate;
-use super::{traits::*, Index};
+use super::{traits::*, Index, StorageVecSnapshot};
 use crate::sync::{AtomicRw, AtomicRwReadGuard, AtomicRwWriteGuard};
+use std::ops::RangeBounds;
 
 /// A wrapper that adds [`RwLock`](std::sync::RwLock) and atomic snapshot
 /// guarantees around all accesses to an ordinary [`Vec`]
@@ -55,6 +56,18 @@ impl<T: Clone> StorageVec<T> for OrdinaryVec<T> {
         self.read_lock().get(index)
     }
 
+    #[inline]
+    fn first(&self) -> Option<T> {
+        let inner = self.read_lock();
+        (!inner.is_empty()).then(|| inner.get(0))
+    }
+
+    #[inline]
+    fn last(&self) -> Option<T> {
+        let inner = self.read_lock();
+        (!inner.is_empty()).then(|| inner.get(inner.len() - 1))
+    }
+
     fn many_iter<'a>(
         &'a self,
         indices: impl IntoIterator<Item = Index> + 'a,
@@ -104,6 +117,16 @@ impl<T: Clone> StorageVec<T> for OrdinaryVec<T> {
         self.write_lock().set_many(key_vals);
     }
 
+    #[inline]
+    fn swap(&mut self, a: Index, b: Index) {
+        self.write_lock().swap(a, b);
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: Index) -> T {
+        self.write_lock().swap_remove(index)
+    }
+
     #[inline]
     fn pop(&mut self) -> Option<T> {
         self.write_lock().pop()
@@ -114,10 +137,47 @@ impl<T: Clone> StorageVec<T> for OrdinaryVec<T> {
         self.write_lock().push(value);
     }
 
+    #[inline]
+    fn extend(&mut self, values: impl IntoIterator<Item = T>) {
+        self.write_lock().extend(values);
+    }
+
+    #[inline]
+    fn pop_many(&mut self, count: usize) -> Vec<T> {
+        self.write_lock().pop_many(count)
+    }
+
+    #[inline]
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.write_lock().retain(f);
+    }
+
     #[inline]
     fn clear(&mut self) {
         self.write_lock().clear();
     }
+
+    #[inline]
+    fn truncate(&mut self, len: Index) {
+        self.write_lock().truncate(len);
+    }
+
+    #[inline]
+    fn resize(&mut self, new_len: Index, value: T) {
+        self.write_lock().resize(new_len, value);
+    }
+
+    #[inline]
+    fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<T> {
+        self.write_lock().drain(range)
+    }
+
+    #[inline]
+    fn restore_from_snapshot(&mut self, snapshot: StorageVecSnapshot<T>) {
+        let mut inner = self.write_lock();
+        inner.clear();
+        inner.extend(snapshot.elements);
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
+use super::super::utils;
 use super::{traits::*, Index};
+use std::ops::RangeBounds;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct OrdinaryVecPrivate<T>(pub(super) Vec<T>);
@@ -33,6 +35,16 @@ impl<T: Clone> OrdinaryVecPrivate<T> {
         }
     }
 
+    #[inline]
+    pub(super) fn swap(&mut self, a: Index, b: Index) {
+        self.0.swap(a as usize, b as usize);
+    }
+
+    #[inline]
+    pub(super) fn swap_remove(&mut self, index: Index) -> T {
+        self.0.swap_remove(index as usize)
+    }
+
     #[inline]
     pub(super) fn pop(&mut self) -> Option<T> {
         self.0.pop()
@@ -43,8 +55,42 @@ impl<T: Clone> OrdinaryVecPrivate<T> {
         self.0.push(value);
     }
 
+    #[inline]
+    pub(super) fn extend(&mut self, values: impl IntoIterator<Item = T>) {
+        self.0.extend(values);
+    }
+
     #[inline]
     pub(super) fn clear(&mut self) {
         self.0.clear();
     }
+
+    #[inline]
+    pub(super) fn truncate(&mut self, len: Index) {
+        self.0.truncate(len as usize);
+    }
+
+    #[inline]
+    pub(super) fn resize(&mut self, new_len: Index, value: T) {
+        self.0.resize(new_len as usize, value);
+    }
+
+    pub(super) fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<T> {
+        let range = utils::resolve_range(range, self.len());
+        let drained: Vec<_> = self
+            .0
+            .drain(range.start as usize..range.end as usize)
+            .collect();
+        drained.into_iter()
+    }
+
+    pub(super) fn pop_many(&mut self, count: usize) -> Vec<T> {
+        let start = self.0.len().saturating_sub(count);
+        self.0.drain(start..).rev().collect()
+    }
+
+    #[inline]
+    pub(super) fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.0.retain(f);
+    }
 }
@@ -0,0 +1,272 @@
+use super::persisted_vec_private::PersistedVecPrivate;
+use super::{traits::*, Index, StorageVecSnapshot};
+use crate::sync::{AtomicRw, AtomicRwReadGuard, AtomicRwWriteGuard};
+use serde::{de::DeserializeOwned, Serialize};
+use std::ops::RangeBounds;
+
+/// A key-value store in which a [`PersistedVec`] persists its elements.
+///
+/// Writes are not part of this trait: every store collects the pending changes of
+/// its vectors in its own kind of batch, see for example `RustyLevelDbVec::pull_queue`.
+pub trait VecStore {
+    /// Return the value stored under `key`, if any.
+    ///
+    /// `name` is the name of the vector reading the value, for use in panic messages.
+    fn get_bytes(&self, key: &[u8], name: &str) -> Option<Vec<u8>>;
+}
+
+/// A concurrency safe Vec backed by a [`VecStore`], with in memory read/write
+/// caching for all operations.
+///
+/// The length is stored under `[key_prefix, 0]`, and the element at index `i`
+/// under `key_prefix` followed by the serialized `i`.
+#[derive(Debug, Clone)]
+pub struct PersistedVec<T: Serialize + DeserializeOwned, S> {
+    inner: AtomicRw<PersistedVecPrivate<T, S>>,
+}
+
+impl<T, S> StorageVec<T> for PersistedVec<T, S>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    S: VecStore,
+{
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.read_lock().is_empty()
+    }
+
+    #[inline]
+    fn len(&self) -> Index {
+        self.read_lock().len()
+    }
+
+    #[inline]
+    fn get(&self, index: Index) -> T {
+        self.read_lock().get(index)
+    }
+
+    #[inline]
+    fn first(&self) -> Option<T> {
+        let inner = self.read_lock();
+        (!inner.is_empty()).then(|| inner.get(0))
+    }
+
+    #[inline]
+    fn last(&self) -> Option<T> {
+        let inner = self.read_lock();
+        (!inner.is_empty()).then(|| inner.get(inner.len() - 1))
+    }
+
+    fn many_iter<'a>(
+        &'a self,
+        indices: impl IntoIterator<Item = Index> + 'a,
+    ) -> Box<dyn Iterator<Item = (Index, T)> + '_> {
+        // note: this lock is moved into the iterator closure and is not
+        //       released until caller drops the returned iterator
+        let inner = self.read_lock();
+
+        Box::new(indices.into_iter().map(move |i| {
+            assert!(
+                i < inner.len(),
+                "Out-of-bounds. Got index {} but length was {}. persisted vector name: {}",
+                i,
+                inner.len(),
+                inner.name
+            );
+
+            if inner.cache.contains_key(&i) {
+                (i, inner.cache[&i].clone())
+            } else {
+                (i, inner.get_persisted(i))
+            }
+        }))
+    }
+
+    fn many_iter_values<'a>(
+        &'a self,
+        indices: impl IntoIterator<Item = Index> + 'a,
+    ) -> Box<dyn Iterator<Item = T> + '_> {
+        // note: this lock is moved into the iterator closure and is not
+        //       released until caller drops the returned iterator
+        let inner = self.read_lock();
+
+        Box::new(indices.into_iter().map(move |i| {
+            assert!(
+                i < inner.len(),
+                "Out-of-bounds. Got index {} but length was {}. persisted vector name: {}",
+                i,
+                inner.len(),
+                inner.name
+            );
+
+            if inner.cache.contains_key(&i) {
+                inner.cache[&i].clone()
+            } else {
+                inner.get_persisted(i)
+            }
+        }))
+    }
+
+    #[inline]
+    fn get_many(&self, indices: &[Index]) -> Vec<T> {
+        self.read_lock().get_many(indices)
+    }
+
+    /// Return all stored elements in a vector, whose index matches the StorageVec's.
+    /// It's the caller's responsibility that there is enough memory to store all elements.
+    #[inline]
+    fn get_all(&self) -> Vec<T> {
+        self.read_lock().get_all()
+    }
+
+    #[inline]
+    fn set(&mut self, index: Index, value: T) {
+        self.write_lock().set(index, value)
+    }
+
+    /// set multiple elements.
+    ///
+    /// panics if key_vals contains an index not in the collection
+    ///
+    /// It is the caller's responsibility to ensure that index values are
+    /// unique.  If not, the last value with the same index will win.
+    /// For unordered collections such as HashMap, the behavior is undefined.
+    #[inline]
+    fn set_many(&mut self, key_vals: impl IntoIterator<Item = (Index, T)>) {
+        self.write_lock().set_many(key_vals)
+    }
+
+    #[inline]
+    fn swap(&mut self, a: Index, b: Index) {
+        self.write_lock().swap(a, b)
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: Index) -> T {
+        self.write_lock().swap_remove(index)
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> {
+        self.write_lock().pop()
+    }
+
+    #[inline]
+    fn push(&mut self, value: T) {
+        self.write_lock().push(value)
+    }
+
+    #[inline]
+    fn extend(&mut self, values: impl IntoIterator<Item = T>) {
+        self.write_lock().extend(values)
+    }
+
+    #[inline]
+    fn pop_many(&mut self, count: usize) -> Vec<T> {
+        self.write_lock().pop_many(count)
+    }
+
+    #[inline]
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.write_lock().retain(f);
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.write_lock().clear();
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: Index) {
+        self.write_lock().truncate(len);
+    }
+
+    #[inline]
+    fn resize(&mut self, new_len: Index, value: T) {
+        self.write_lock().resize(new_len, value);
+    }
+
+    #[inline]
+    fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<T> {
+        self.write_lock().drain(range)
+    }
+
+    #[inline]
+    fn restore_from_snapshot(&mut self, snapshot: StorageVecSnapshot<T>) {
+        let mut inner = self.write_lock();
+        inner.clear();
+        inner.extend(snapshot.elements);
+    }
+}
+
+impl<T: Serialize + DeserializeOwned, S> PersistedVec<T, S> {
+    #[inline]
+    pub(crate) fn write_lock(&mut self) -> AtomicRwWriteGuard<'_, PersistedVecPrivate<T, S>> {
+        self.inner.lock_guard_mut()
+    }
+
+    #[inline]
+    pub(crate) fn read_lock(&self) -> AtomicRwReadGuard<'_, PersistedVecPrivate<T, S>> {
+        self.inner.lock_guard()
+    }
+}
+
+impl<T: Serialize + DeserializeOwned, S> StorageVecRwLock<T> for PersistedVec<T, S> {
+    type LockedData = PersistedVecPrivate<T, S>;
+
+    #[inline]
+    fn try_write_lock(&mut self) -> Option<AtomicRwWriteGuard<'_, Self::LockedData>> {
+        Some(self.write_lock())
+    }
+
+    #[inline]
+    fn try_read_lock(&self) -> Option<AtomicRwReadGuard<'_, Self::LockedData>> {
+        Some(self.read_lock())
+    }
+}
+
+impl<T, S> PersistedVec<T, S>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    S: VecStore,
+{
+    // Return the key used to store the length of the persisted vector
+    #[inline]
+    pub fn get_length_key(key_prefix: u8) -> [u8; 2] {
+        PersistedVecPrivate::<T, S>::get_length_key(key_prefix)
+    }
+
+    /// Return the length at the last write to disk
+    #[inline]
+    pub fn persisted_length(&self) -> Index {
+        self.read_lock().persisted_length()
+    }
+
+    /// Return the key used to store the element at an index
+    #[inline]
+    pub fn get_index_key(&self, index: Index) -> [u8; 9] {
+        self.read_lock().get_index_key(index)
+    }
+
+    #[inline]
+    pub fn new(store: S, key_prefix: u8, name: &str) -> Self {
+        Self {
+            inner: AtomicRw::from(PersistedVecPrivate::<T, S>::new(store, key_prefix, name)),
+        }
+    }
+
+    /// Like [`new`](Self::new), but additionally caches up to `capacity`
+    /// elements read from the store, evicting the least recently used
+    /// ones first. A `capacity` of 0 disables the read cache.
+    ///
+    /// Elements that have been written but not yet persisted are never
+    /// evicted; they are retained until the write queue is pulled.
+    #[inline]
+    pub fn with_cache_capacity(store: S, key_prefix: u8, name: &str, capacity: usize) -> Self {
+        let vec =
+            PersistedVecPrivate::<T, S>::with_cache_capacity(store, key_prefix, name, capacity);
+        Self {
+            inner: AtomicRw::from(vec),
+        }
+    }
+}
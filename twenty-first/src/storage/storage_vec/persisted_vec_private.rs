@@ -0,0 +1,550 @@
+use super::super::utils;
+use super::{traits::*, Index, VecStore};
+use itertools::Itertools;
+use lru::LruCache;
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::ops::RangeBounds;
+use std::sync::Mutex;
+
+/// This is the private impl of PersistedVec.
+///
+/// PersistedVec is a public wrapper that adds RwLock around
+/// all accesses to PersistedVecPrivate
+//
+// note: this is `pub` because it is exposed in public API by `ManyIterMut`
+// but we keep all the fields private.
+#[derive(Debug, Clone)]
+pub struct PersistedVecPrivate<T: Serialize + DeserializeOwned, S> {
+    key_prefix: u8,
+    store: S,
+    write_queue: VecDeque<WriteElement<T>>,
+    length: Index,
+    pub(super) cache: HashMap<Index, T>,
+    read_cache: Option<ReadCache<T>>,
+    pub(super) name: String,
+}
+
+/// A bounded cache of elements read from the store.
+///
+/// Unlike `PersistedVecPrivate::cache`, which holds elements that have
+/// been written but not yet persisted, entries of this cache are always
+/// identical to their persisted counterpart and can be evicted at any time.
+//
+// note: reads happen through a shared reference while holding the read
+// lock of `PersistedVec`, hence the interior mutability.
+#[derive(Debug)]
+struct ReadCache<T>(Mutex<LruCache<Index, T>>);
+
+impl<T: Clone> Clone for ReadCache<T> {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.lock().clone()))
+    }
+}
+
+impl<T: Clone> ReadCache<T> {
+    fn new(capacity: NonZeroUsize) -> Self {
+        Self(Mutex::new(LruCache::new(capacity)))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<Index, T>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn get(&self, index: Index) -> Option<T> {
+        self.lock().get(&index).cloned()
+    }
+
+    fn put(&self, index: Index, value: T) {
+        self.lock().put(index, value);
+    }
+
+    fn invalidate(&mut self, index: Index) {
+        self.0
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .pop(&index);
+    }
+}
+
+/// A change to the store that persists part of the write queue.
+pub(super) enum PendingWrite {
+    Put { key: Vec<u8>, value: Vec<u8> },
+    Delete { key: Vec<u8> },
+}
+
+impl<T, S> StorageVecLockedData<T> for PersistedVecPrivate<T, S>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    S: VecStore,
+{
+    #[inline]
+    fn get(&self, index: Index) -> T {
+        // Disallow getting values out-of-bounds
+        assert!(
+            index < self.len(),
+            "Out-of-bounds. Got {index} but length was {}. persisted vector name: {}",
+            self.length,
+            self.name
+        );
+
+        // try cache first
+        if self.cache.contains_key(&index) {
+            return self.cache[&index].clone();
+        }
+
+        // then try persistent storage
+        self.get_persisted(index)
+    }
+
+    #[inline]
+    fn set(&mut self, index: Index, value: T) {
+        // Disallow setting values out-of-bounds
+        assert!(
+            index < self.len(),
+            "Out-of-bounds. Got {index} but length was {}. persisted vector name: {}",
+            self.length,
+            self.name
+        );
+
+        self.cache.insert(index, value.clone());
+        self.invalidate_read_cache(index);
+
+        // note: benchmarks have revealed this code to slow down
+        //       set operations by about 7x, eg 10us to 70us.
+        //       Disabling for now.
+        //
+        // if let Some(_old_val) = self.cache.insert(index, value.clone()) {
+        //     // If cache entry exists, we remove any corresponding
+        //     // OverWrite ops in the `write_queue` to reduce disk IO.
+
+        //     // logic: retain all ops that are not overwrite, and
+        //     // overwrite ops that do not have an index matching cache_index.
+        //     self.write_queue.retain(|op| match op {
+        //         WriteElement::OverWrite((i, _)) => *i != index,
+        //         _ => true,
+        //     })
+        // }
+
+        self.write_queue
+            .push_back(WriteElement::OverWrite((index, value)));
+    }
+}
+
+impl<T, S> PersistedVecPrivate<T, S>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    S: VecStore,
+{
+    #[inline]
+    pub(super) fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[inline]
+    pub(super) fn len(&self) -> Index {
+        self.length
+    }
+
+    pub(super) fn get_many(&self, indices: &[Index]) -> Vec<T> {
+        fn sort_to_match_requested_index_order<T>(indexed_elements: HashMap<usize, T>) -> Vec<T> {
+            let mut elements = indexed_elements.into_iter().collect_vec();
+            elements.sort_unstable_by_key(|&(index_position, _)| index_position);
+            elements.into_iter().map(|(_, element)| element).collect()
+        }
+
+        let max_index = match indices.iter().max() {
+            Some(i) => i,
+            None => return vec![],
+        };
+
+        assert!(
+            *max_index < self.len(),
+            "Out-of-bounds. Got index {max_index} but length was {}. persisted vector name: {}",
+            self.len(),
+            self.name
+        );
+
+        let (indices_of_elements_in_cache, indices_of_elements_not_in_cache): (Vec<_>, Vec<_>) =
+            indices
+                .iter()
+                .copied()
+                .enumerate()
+                .partition(|&(_, index)| self.cache.contains_key(&index));
+
+        let mut fetched_elements = HashMap::with_capacity(indices.len());
+        for (index_position, index) in indices_of_elements_in_cache {
+            let element = self.cache[&index].clone();
+            fetched_elements.insert(index_position, element);
+        }
+
+        let no_need_to_lock_database = indices_of_elements_not_in_cache.is_empty();
+        if no_need_to_lock_database {
+            return sort_to_match_requested_index_order(fetched_elements);
+        }
+
+        let elements_fetched_from_db = indices_of_elements_not_in_cache
+            .iter()
+            .map(|&(_, index)| self.get_persisted(index));
+
+        let indexed_fetched_elements_from_db = indices_of_elements_not_in_cache
+            .iter()
+            .map(|&(index_position, _)| index_position)
+            .zip_eq(elements_fetched_from_db);
+        fetched_elements.extend(indexed_fetched_elements_from_db);
+
+        sort_to_match_requested_index_order(fetched_elements)
+    }
+
+    /// Return all stored elements in a vector, whose index matches the StorageVec's.
+    /// It's the caller's responsibility that there is enough memory to store all elements.
+    pub(super) fn get_all(&self) -> Vec<T> {
+        let length = self.len();
+
+        let (indices_of_elements_in_cache, indices_of_elements_not_in_cache): (Vec<_>, Vec<_>) =
+            (0..length).partition(|index| self.cache.contains_key(index));
+
+        let mut fetched_elements: Vec<Option<T>> = vec![None; length as usize];
+        for index in indices_of_elements_in_cache {
+            let element = self.cache[&index].clone();
+            fetched_elements[index as usize] = Some(element);
+        }
+
+        let no_need_to_lock_database = indices_of_elements_not_in_cache.is_empty();
+        if no_need_to_lock_database {
+            return fetched_elements
+                .into_iter()
+                .map(|x| x.expect("should get some element"))
+                .collect_vec();
+        }
+
+        for index in indices_of_elements_not_in_cache {
+            let element = self.get_persisted(index);
+            fetched_elements[index as usize] = Some(element);
+        }
+
+        fetched_elements
+            .into_iter()
+            .map(|x| x.expect("should get some element"))
+            .collect_vec()
+    }
+
+    /// set multiple elements.
+    ///
+    /// panics if key_vals contains an index not in the collection
+    ///
+    /// It is the caller's responsibility to ensure that index values are
+    /// unique.  If not, the last value with the same index will win.
+    /// For unordered collections such as HashMap, the behavior is undefined.
+    #[inline]
+    pub(super) fn set_many(&mut self, key_vals: impl IntoIterator<Item = (Index, T)>) {
+        for (index, value) in key_vals.into_iter() {
+            self.set(index, value);
+        }
+    }
+
+    pub(super) fn swap(&mut self, a: Index, b: Index) {
+        let value_a = self.get(a);
+        let value_b = self.get(b);
+        self.set(a, value_b);
+        self.set(b, value_a);
+    }
+
+    pub(super) fn swap_remove(&mut self, index: Index) -> T {
+        assert!(
+            index < self.len(),
+            "Out-of-bounds. Got {index} but length was {}. persisted vector name: {}",
+            self.length,
+            self.name
+        );
+
+        let last = self.pop().expect("vector should be non-empty");
+        if index == self.length {
+            return last;
+        }
+
+        let removed = self.get(index);
+        self.set(index, last);
+        removed
+    }
+
+    #[inline]
+    pub(super) fn pop(&mut self) -> Option<T> {
+        // If vector is empty, return None
+        if self.length == 0 {
+            return None;
+        }
+
+        // add to write queue
+        self.write_queue.push_back(WriteElement::Pop);
+
+        // Update length
+        self.length -= 1;
+
+        // try cache first, then persistent storage
+        let value = match self.cache.remove(&self.length) {
+            Some(value) => value,
+            None => self.get_persisted(self.length),
+        };
+        self.invalidate_read_cache(self.length);
+
+        Some(value)
+    }
+
+    #[inline]
+    pub(super) fn push(&mut self, value: T) {
+        // add to write queue
+        self.write_queue
+            .push_back(WriteElement::Push(value.clone()));
+
+        // record in cache
+        let _old_value = self.cache.insert(self.length, value);
+        self.invalidate_read_cache(self.length);
+
+        // note: we cannot naively remove any previous `Push` ops with
+        // this value from the write_queue (to reduce disk i/o) because
+        // there might be corresponding `Pop` op(s).
+
+        // update length
+        self.length += 1;
+    }
+
+    pub(super) fn extend(&mut self, values: impl IntoIterator<Item = T>) {
+        let mut length = self.length;
+        for value in values {
+            self.write_queue
+                .push_back(WriteElement::Push(value.clone()));
+            self.cache.insert(length, value);
+            self.invalidate_read_cache(length);
+            length += 1;
+        }
+
+        self.length = length;
+    }
+
+    #[inline]
+    pub(super) fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub(super) fn truncate(&mut self, len: Index) {
+        if len >= self.length {
+            return;
+        }
+
+        // Pending overwrites of dropped elements would be deleted again
+        // when the queue is persisted, so there is no point in writing them.
+        self.write_queue.retain(|op| match op {
+            WriteElement::OverWrite((i, _)) => *i < len,
+            _ => true,
+        });
+
+        // Unlike `pop`, don't read the dropped elements. Each `Pop` deletes
+        // the key of the then-last element when the queue is persisted.
+        for index in (len..self.length).rev() {
+            self.cache.remove(&index);
+            self.invalidate_read_cache(index);
+            self.write_queue.push_back(WriteElement::Pop);
+        }
+
+        self.length = len;
+    }
+
+    pub(super) fn resize(&mut self, new_len: Index, value: T) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        for _ in self.length..new_len {
+            self.push(value.clone());
+        }
+    }
+
+    pub(super) fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<T> {
+        let range = utils::resolve_range(range, self.len());
+        let drained = self.get_many(&range.clone().collect_vec());
+
+        // shift the elements after the range down to close the gap
+        let tail = self.get_many(&(range.end..self.len()).collect_vec());
+        let new_len = range.start + tail.len() as Index;
+        self.set_many((range.start..).zip(tail));
+        self.truncate(new_len);
+
+        drained.into_iter()
+    }
+
+    pub(super) fn pop_many(&mut self, count: usize) -> Vec<T> {
+        let start = self.len().saturating_sub(count as Index);
+        self.drain(start..).rev().collect()
+    }
+
+    /// Only elements that change their index are written.
+    pub(super) fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut num_retained = 0;
+        let mut moved_elements = vec![];
+        for (index, element) in (0..).zip(self.get_all()) {
+            if !f(&element) {
+                continue;
+            }
+            if index != num_retained {
+                moved_elements.push((num_retained, element));
+            }
+            num_retained += 1;
+        }
+
+        self.set_many(moved_elements);
+        self.truncate(num_retained);
+    }
+}
+
+// ************ non-trait methods (StorageVec) **************/
+impl<T, S> PersistedVecPrivate<T, S>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    S: VecStore,
+{
+    #[inline]
+    pub(super) fn with_cache_capacity(
+        store: S,
+        key_prefix: u8,
+        name: &str,
+        capacity: usize,
+    ) -> Self {
+        let mut vec = Self::new(store, key_prefix, name);
+        vec.read_cache = NonZeroUsize::new(capacity).map(ReadCache::new);
+        vec
+    }
+
+    /// Read the persisted element at the given index, going through the read
+    /// cache if there is one.
+    ///
+    /// Does not consider elements that have been written but not persisted.
+    pub(super) fn get_persisted(&self, index: Index) -> T {
+        if let Some(element) = self.read_cache.as_ref().and_then(|c| c.get(index)) {
+            return element;
+        }
+
+        let key = self.get_index_key(index);
+        let bytes = self.store.get_bytes(&key, &self.name).unwrap_or_else(|| {
+            panic!(
+                "Element with index {index} does not exist in {}. This should not happen",
+                self.name
+            )
+        });
+        let element: T = utils::deserialize(&bytes);
+        if let Some(read_cache) = &self.read_cache {
+            read_cache.put(index, element.clone());
+        }
+        element
+    }
+
+    #[inline]
+    fn invalidate_read_cache(&mut self, index: Index) {
+        if let Some(read_cache) = &mut self.read_cache {
+            read_cache.invalidate(index);
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned, S: VecStore> PersistedVecPrivate<T, S> {
+    // Return the key used to store the length of the persisted vector
+    #[inline]
+    pub(super) fn get_length_key(key_prefix: u8) -> [u8; 2] {
+        const LENGTH_KEY: u8 = 0u8;
+        [key_prefix, LENGTH_KEY]
+    }
+
+    /// Return the length at the last write to disk
+    #[inline]
+    pub(super) fn persisted_length(&self) -> Index {
+        Self::persisted_length_in_store(&self.store, self.key_prefix, &self.name)
+    }
+
+    fn persisted_length_in_store(store: &S, key_prefix: u8, name: &str) -> Index {
+        let key = Self::get_length_key(key_prefix);
+        match store.get_bytes(&key, name) {
+            Some(length_bytes) => utils::deserialize(&length_bytes),
+            None => 0,
+        }
+    }
+
+    /// Return the key used to store the element at an index
+    #[inline]
+    pub(super) fn get_index_key(&self, index: Index) -> [u8; 9] {
+        [vec![self.key_prefix], utils::serialize(&index)]
+            .concat()
+            .try_into()
+            .expect("should convert index key into [u8; 9]")
+    }
+
+    #[inline]
+    pub(super) fn new(store: S, key_prefix: u8, name: &str) -> Self {
+        let length = Self::persisted_length_in_store(&store, key_prefix, name);
+        Self {
+            key_prefix,
+            store,
+            write_queue: VecDeque::default(),
+            length,
+            cache: HashMap::new(),
+            read_cache: None,
+            name: name.to_string(),
+        }
+    }
+
+    /// Return the changes to the store that persist all elements that have not yet
+    /// been persisted, without removing them from the write queue.
+    pub(super) fn pending_writes(&self) -> Vec<PendingWrite> {
+        let original_length = self.persisted_length();
+        let mut length = original_length;
+        let mut pending_writes = Vec::with_capacity(self.write_queue.len() + 1);
+        for write_element in self.write_queue.iter() {
+            let pending_write = match write_element {
+                WriteElement::OverWrite((i, t)) => PendingWrite::Put {
+                    key: self.get_index_key(*i).to_vec(),
+                    value: utils::serialize(t),
+                },
+                WriteElement::Push(t) => {
+                    let key = self.get_index_key(length).to_vec();
+                    length += 1;
+                    PendingWrite::Put {
+                        key,
+                        value: utils::serialize(t),
+                    }
+                }
+                WriteElement::Pop => {
+                    let key = self.get_index_key(length - 1).to_vec();
+                    length -= 1;
+                    PendingWrite::Delete { key }
+                }
+            };
+            pending_writes.push(pending_write);
+        }
+
+        if original_length != length {
+            pending_writes.push(PendingWrite::Put {
+                key: Self::get_length_key(self.key_prefix).to_vec(),
+                value: utils::serialize(&self.length),
+            });
+        }
+
+        pending_writes
+    }
+
+    /// Drop all elements that have not yet been persisted from the write queue. Should
+    /// only be called once they have been written to the store.
+    pub(super) fn clear_queue(&mut self) {
+        self.write_queue.clear();
+        self.cache.clear();
+    }
+}
+
+#[derive(Debug, Clone)]
+enum WriteElement<T: Serialize + DeserializeOwned> {
+    OverWrite((Index, T)),
+    Push(T),
+    Pop,
+}
@@ -0,0 +1,209 @@
+use std::marker::PhantomData;
+
+use super::traits::*;
+use super::Index;
+
+/// A ring buffer holding the `CAP` most recently pushed elements, backed by a [`StorageVec`].
+/// Once full, every push overwrites the oldest element.
+///
+/// Every slot of the backing vector stores its element together with the element's sequence
+/// number, _i.e._, the number of elements pushed before it. This way, the position of the oldest
+/// element, the buffer's head, is persisted implicitly and atomically with the elements
+/// themselves. It is recovered when re-opening the buffer using [`new`](Self::new).
+///
+/// The backing vector must not be modified other than through the ring buffer.
+#[derive(Debug)]
+pub struct RingBuffer<T, V, const CAP: usize> {
+    slots: V,
+    next_sequence_number: Index,
+    _element: PhantomData<T>,
+}
+
+impl<T, V, const CAP: usize> RingBuffer<T, V, CAP>
+where
+    T: Clone,
+    V: StorageVec<(Index, T)>,
+{
+    /// Create a ring buffer backed by the given vector, which is either empty or was previously
+    /// populated by a ring buffer of the same capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `CAP` is 0 or if the backing vector holds more than `CAP` elements.
+    pub fn new(slots: V) -> Self {
+        assert!(CAP > 0, "capacity must be positive");
+        assert!(
+            slots.len() <= Self::capacity(),
+            "backing vector must not hold more than {} elements",
+            CAP
+        );
+
+        let next_sequence_number = Self::recover_next_sequence_number(&slots);
+        Self {
+            slots,
+            next_sequence_number,
+            _element: PhantomData,
+        }
+    }
+
+    /// Before wrapping around, slots are filled in order. Afterwards, the sequence number of
+    /// the element in slot `i` is `s + i` for all slots before the head and `s + i - CAP` for
+    /// all slots starting at the head, where `s` is the sequence number of the element in slot
+    /// 0. The head is found using binary search.
+    fn recover_next_sequence_number(slots: &V) -> Index {
+        if slots.len() < Self::capacity() {
+            return slots.len();
+        }
+
+        let (first_sequence_number, _) = slots.get(0);
+        let is_before_head = |i| slots.get(i).0 == first_sequence_number + i;
+        let (mut low, mut high) = (1, Self::capacity());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if is_before_head(mid) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        first_sequence_number + low
+    }
+
+    /// The maximum number of elements the ring buffer holds.
+    pub const fn capacity() -> Index {
+        CAP as Index
+    }
+
+    /// The number of elements currently held, which never exceeds the
+    /// [capacity](Self::capacity).
+    pub fn len(&self) -> Index {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// The total number of elements ever pushed, including overwritten ones.
+    pub fn num_pushed(&self) -> Index {
+        self.next_sequence_number
+    }
+
+    /// The backing vector, for example to persist it.
+    pub fn inner(&self) -> &V {
+        &self.slots
+    }
+
+    /// The backing vector, for example to persist it. Modifying the vector other than by
+    /// persisting it corrupts the ring buffer.
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.slots
+    }
+
+    pub fn into_inner(self) -> V {
+        self.slots
+    }
+
+    /// Push an element, overwriting the oldest element if the ring buffer is full.
+    pub fn push(&mut self, value: T) {
+        let slot = self.next_sequence_number % Self::capacity();
+        let entry = (self.next_sequence_number, value);
+        if slot == self.slots.len() {
+            self.slots.push(entry);
+        } else {
+            self.slots.set(slot, entry);
+        }
+        self.next_sequence_number += 1;
+    }
+
+    /// Iterate over all held elements, from oldest to newest.
+    ///
+    /// The returned iterator holds a read-lock over the backing vector, see
+    /// [`many_iter_values`](StorageVec::many_iter_values).
+    pub fn iter_chronological(&self) -> impl Iterator<Item = T> + '_ {
+        let head = if self.len() < Self::capacity() {
+            0
+        } else {
+            self.next_sequence_number % Self::capacity()
+        };
+        let indices = (head..self.len()).chain(0..head);
+        self.slots.many_iter_values(indices).map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use leveldb::batch::WriteBatch;
+
+    use super::super::tests::get_test_db;
+    use super::super::OrdinaryVec;
+    use super::super::RustyLevelDbVec;
+    use super::*;
+
+    const CAP: usize = 5;
+
+    #[test]
+    fn ring_buffer_over_ordinary_vec_holds_last_pushed_elements_in_order() {
+        let ordinary_vec = OrdinaryVec::<(Index, u64)>::from(vec![]);
+        let mut ring_buffer = RingBuffer::<u64, _, CAP>::new(ordinary_vec);
+        assert!(ring_buffer.is_empty());
+        assert_eq!(0, ring_buffer.iter_chronological().count());
+
+        for value in 0..CAP as u64 + 3 {
+            ring_buffer.push(value);
+            let first_held = (value + 1).saturating_sub(CAP as u64);
+            let expected = (first_held..=value).collect_vec();
+            assert_eq!(expected, ring_buffer.iter_chronological().collect_vec());
+        }
+        assert_eq!(CAP as u64, ring_buffer.len());
+        assert_eq!(CAP as u64 + 3, ring_buffer.num_pushed());
+    }
+
+    #[test]
+    fn ring_buffer_over_rusty_leveldb_vec_persists_head() {
+        let mut db = get_test_db(true);
+        let vec_name = "ring buffer test vec";
+        let persisted_vec = RustyLevelDbVec::<(Index, u64)>::new(db.clone(), 0, vec_name);
+        let mut ring_buffer = RingBuffer::<u64, _, CAP>::new(persisted_vec);
+        ring_buffer.push(100);
+        ring_buffer.push(101);
+
+        for num_additional_pushes in 0..3 * CAP as u64 {
+            let write_batch = WriteBatch::new();
+            ring_buffer.inner_mut().pull_queue(&write_batch);
+            assert!(db.write_auto(&write_batch).is_ok());
+
+            let reloaded_vec = RustyLevelDbVec::<(Index, u64)>::new(db.clone(), 0, vec_name);
+            let reloaded_ring_buffer = RingBuffer::<u64, _, CAP>::new(reloaded_vec);
+            assert_eq!(ring_buffer.num_pushed(), reloaded_ring_buffer.num_pushed());
+            assert_eq!(
+                ring_buffer.iter_chronological().collect_vec(),
+                reloaded_ring_buffer.iter_chronological().collect_vec()
+            );
+
+            ring_buffer.push(num_additional_pushes);
+        }
+    }
+
+    #[test]
+    fn ring_buffer_over_rusty_leveldb_vec_holds_last_pushed_elements_in_order() {
+        let db = get_test_db(true);
+        let persisted_vec = RustyLevelDbVec::<(Index, u64)>::new(db, 0, "ring buffer test vec");
+        let mut ring_buffer = RingBuffer::<u64, _, CAP>::new(persisted_vec);
+        for value in 0..CAP as u64 + 3 {
+            ring_buffer.push(value);
+        }
+
+        let expected = (3..CAP as u64 + 3).collect_vec();
+        assert_eq!(expected, ring_buffer.iter_chronological().collect_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "backing vector must not hold more than 5 elements")]
+    fn ring_buffer_rejects_overly_long_backing_vector() {
+        let slots = (0..CAP as u64 + 1).map(|i| (i, i)).collect_vec();
+        RingBuffer::<u64, _, CAP>::new(OrdinaryVec::from(slots));
+    }
+}
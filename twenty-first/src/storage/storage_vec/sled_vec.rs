@@ -0,0 +1,91 @@
+use super::PersistedVec;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A concurrency safe Vec backed by a [`sled::Tree`], with in memory read/write
+/// caching for all operations.
+///
+/// Uses the same key layout as [`RustyLevelDbVec`](super::RustyLevelDbVec).
+pub type SledVec<T> = PersistedVec<T, sled::Tree>;
+
+impl<T: Serialize + DeserializeOwned + Clone> SledVec<T> {
+    /// Collect all added elements that have not yet been persisted.
+    ///
+    /// The batch must be applied to the tree with [`sled::Tree::apply_batch`]
+    /// for the changes to be persisted atomically.
+    #[inline]
+    pub fn pull_queue(&mut self, batch: &mut sled::Batch) {
+        self.write_lock().pull_queue(batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::traits::tests as traits_tests;
+    use super::super::traits::*;
+    use super::*;
+
+    fn get_test_tree() -> sled::Tree {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        db.open_tree("test-tree").unwrap()
+    }
+
+    #[test]
+    fn persisted_elements_survive_reload() {
+        let tree = get_test_tree();
+        let mut vec: SledVec<u64> = SledVec::new(tree.clone(), 0, "test-vec");
+        vec.extend(0..100);
+        vec.set(3, 1776);
+        vec.swap_remove(5);
+        assert_eq!(0, vec.persisted_length());
+
+        let mut batch = sled::Batch::default();
+        vec.pull_queue(&mut batch);
+        tree.apply_batch(batch).unwrap();
+        assert_eq!(99, vec.persisted_length());
+
+        let reloaded_vec: SledVec<u64> = SledVec::new(tree, 0, "test-vec");
+        assert_eq!(vec.get_all(), reloaded_vec.get_all());
+        assert_eq!(1776, reloaded_vec.get(3));
+        assert_eq!(99, reloaded_vec.get(5));
+    }
+
+    #[should_panic(
+        expected = "Out-of-bounds. Got 2 but length was 2. persisted vector name: test-vec"
+    )]
+    #[test]
+    fn panic_on_out_of_bounds_get() {
+        let mut vec: SledVec<u64> = SledVec::new(get_test_tree(), 0, "test-vec");
+        vec.extend([1, 2]);
+        vec.get(2);
+    }
+
+    mod concurrency {
+        use super::*;
+
+        fn gen_concurrency_test_vec() -> SledVec<u64> {
+            SledVec::new(get_test_tree(), 0, "test-vec")
+        }
+
+        #[test]
+        fn atomic_set_and_get_wrapped_atomic_rw() {
+            traits_tests::concurrency::atomic_set_and_get_wrapped_atomic_rw(
+                &mut gen_concurrency_test_vec(),
+            );
+        }
+
+        #[test]
+        fn atomic_setmany_and_getmany() {
+            traits_tests::concurrency::atomic_setmany_and_getmany(&mut gen_concurrency_test_vec());
+        }
+
+        #[test]
+        fn atomic_setall_and_getall() {
+            traits_tests::concurrency::atomic_setall_and_getall(&mut gen_concurrency_test_vec());
+        }
+
+        #[test]
+        fn atomic_iter_mut_and_iter() {
+            traits_tests::concurrency::atomic_iter_mut_and_iter(&mut gen_concurrency_test_vec());
+        }
+    }
+}
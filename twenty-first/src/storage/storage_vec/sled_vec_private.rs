@@ -0,0 +1,32 @@
+use super::persisted_vec_private::{PendingWrite, PersistedVecPrivate};
+use super::VecStore;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// This is the private impl of SledVec.
+///
+/// SledVec is a public wrapper that adds RwLock around
+/// all accesses to SledVecPrivate
+pub type SledVecPrivate<T> = PersistedVecPrivate<T, sled::Tree>;
+
+impl VecStore for sled::Tree {
+    fn get_bytes(&self, key: &[u8], name: &str) -> Option<Vec<u8>> {
+        match self.get(key) {
+            Ok(bytes) => bytes.map(|bytes| bytes.to_vec()),
+            Err(e) => panic!("DB Error retrieving key {key:?} of {name}. error: {e}"),
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> SledVecPrivate<T> {
+    /// Collect all added elements that have not yet been persisted
+    pub(crate) fn pull_queue(&mut self, batch: &mut sled::Batch) {
+        for pending_write in self.pending_writes() {
+            match pending_write {
+                PendingWrite::Put { key, value } => batch.insert(key, value),
+                PendingWrite::Delete { key } => batch.remove(key),
+            }
+        }
+
+        self.clear_queue();
+    }
+}
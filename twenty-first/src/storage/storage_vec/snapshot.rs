@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The contents of a [`StorageVec`](super::traits::StorageVec) at some point in
+/// time, for example for debugging or to dump a collection and reload it later.
+///
+/// See [`snapshot`](super::traits::StorageVec::snapshot) and
+/// [`restore_from_snapshot`](super::traits::StorageVec::restore_from_snapshot).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StorageVecSnapshot<T> {
+    pub elements: Vec<T>,
+}
@@ -4,8 +4,10 @@
 //! `use twenty_first::storage::storage_vec::traits::*`
 
 // use super::iterators::{ManyIterMut, StorageSetter};
-use super::{Index, ManyIterMut};
+use super::{Index, ManyIterMut, StorageVecSnapshot};
 use crate::sync::{AtomicRwReadGuard, AtomicRwWriteGuard};
+use std::cmp::Ordering;
+use std::ops::RangeBounds;
 
 // re-export to make life easier for users of our API.
 pub use lending_iterator::LendingIterator;
@@ -20,6 +22,12 @@ pub trait StorageVec<T> {
     /// get single element at index
     fn get(&self, index: Index) -> T;
 
+    /// get the first element, or `None` if the collection is empty
+    fn first(&self) -> Option<T>;
+
+    /// get the last element, or `None` if the collection is empty
+    fn last(&self) -> Option<T>;
+
     /// get multiple elements matching indices
     ///
     /// This is a convenience method. For large collections
@@ -40,6 +48,23 @@ pub trait StorageVec<T> {
         self.iter().map(|(_i, v)| v).collect()
     }
 
+    /// get all elements. Same as [`get_all`](Self::get_all).
+    #[inline]
+    fn to_vec(&self) -> Vec<T> {
+        self.get_all()
+    }
+
+    /// get a serializable snapshot of all elements
+    ///
+    /// The snapshot is consistent: all elements are read while holding
+    /// the same read-lock.
+    #[inline]
+    fn snapshot(&self) -> StorageVecSnapshot<T> {
+        StorageVecSnapshot {
+            elements: self.get_all(),
+        }
+    }
+
     /// get an iterator over all elements
     ///
     /// The returned iterator holds a read-lock over the collection contents.
@@ -146,6 +171,64 @@ pub trait StorageVec<T> {
         indices: impl IntoIterator<Item = Index> + 'a,
     ) -> Box<dyn Iterator<Item = T> + '_>;
 
+    /// Returns `true` if the collection contains an element equal to `value`.
+    ///
+    /// Elements are read in order and the scan stops at the first match.
+    ///
+    /// note: the scan holds a read-lock over the collection contents, so
+    ///       no writes can happen while it is ongoing.
+    #[inline]
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.position(|element| element == value).is_some()
+    }
+
+    /// Returns the index of the first element for which the predicate
+    /// returns `true`, or `None` if there is no such element.
+    ///
+    /// Elements are read in order and the scan stops at the first match.
+    ///
+    /// note: the scan holds a read-lock over the collection contents, so
+    ///       no writes can happen while it is ongoing.
+    #[inline]
+    fn position<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<Index> {
+        self.iter()
+            .find(|(_, element)| f(element))
+            .map(|(index, _)| index)
+    }
+
+    /// Binary searches this collection, which must be sorted with respect to
+    /// the comparator function `f`, like [`slice::binary_search_by`].
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the
+    /// index at which a matching element could be inserted while maintaining
+    /// sorted order. If there are multiple matches, any one of them may be
+    /// returned.
+    ///
+    /// Elements are probed individually using [`get`](Self::get), so only
+    /// `O(log n)` elements are read.
+    ///
+    /// note: every probe is a separate read operation. Concurrent writes
+    ///       between probes can lead to inconsistent results.
+    fn binary_search_by<F>(&self, mut f: F) -> Result<Index, Index>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&self.get(mid)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
     /// set a single element.
     ///
     /// note: The update is performed as a single atomic operation.
@@ -197,6 +280,23 @@ pub trait StorageVec<T> {
         self.set_first_n(iter);
     }
 
+    /// swap the elements at indices `a` and `b`
+    ///
+    /// panics if either index is out of bounds.
+    ///
+    /// note: The update is performed as a single atomic operation.
+    fn swap(&mut self, a: Index, b: Index);
+
+    /// remove the element at `index` and return it, replacing it with the
+    /// last element of the collection.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// panics if index is out of bounds.
+    ///
+    /// note: The update is performed as a single atomic operation.
+    fn swap_remove(&mut self, index: Index) -> T;
+
     /// pop an element from end of collection
     ///
     /// note: The update is performed as a single atomic operation.
@@ -207,11 +307,86 @@ pub trait StorageVec<T> {
     /// note: The update is performed as a single atomic operation.
     fn push(&mut self, value: T);
 
+    /// push all elements of an iterator to end of collection
+    ///
+    /// note: all updates are performed as a single atomic operation.
+    ///       readers will see either the before or after state,
+    ///       never an intermediate state.
+    fn extend(&mut self, values: impl IntoIterator<Item = T>);
+
+    /// push all elements of an iterator to end of collection. Equivalent to
+    /// [`extend`](Self::extend).
+    ///
+    /// note: all updates are performed as a single atomic operation.
+    ///       readers will see either the before or after state,
+    ///       never an intermediate state.
+    #[inline]
+    fn push_many(&mut self, values: impl IntoIterator<Item = T>) {
+        self.extend(values);
+    }
+
+    /// pop up to `count` elements from end of collection. The elements are
+    /// returned in the order they are popped, _i.e._, the last element first.
+    ///
+    /// Returns fewer than `count` elements if the collection is shorter.
+    ///
+    /// note: all updates are performed as a single atomic operation.
+    ///       readers will see either the before or after state,
+    ///       never an intermediate state.
+    fn pop_many(&mut self, count: usize) -> Vec<T>;
+
+    /// Retains only the elements for which the predicate returns `true`,
+    /// preserving their order. The surviving elements are moved to the
+    /// front of the collection, and the collection is shortened accordingly.
+    ///
+    /// note: all updates are performed as a single atomic operation.
+    ///       readers will see either the before or after state,
+    ///       never an intermediate state.
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F);
+
     /// Removes all elements from the collection
     ///
     /// note: The update is performed as a single atomic operation.
     fn clear(&mut self);
 
+    /// Shortens the collection, keeping the first `len` elements and dropping
+    /// the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to the collection's
+    /// current length.
+    ///
+    /// note: The update is performed as a single atomic operation.
+    fn truncate(&mut self, len: Index);
+
+    /// Resizes the collection so that its length is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the collection is
+    /// extended with clones of `value`. If `new_len` is less than the current
+    /// length, the collection is truncated.
+    ///
+    /// note: The update is performed as a single atomic operation.
+    fn resize(&mut self, new_len: Index, value: T)
+    where
+        T: Clone;
+
+    /// Removes the elements in `range` from the collection and returns them
+    /// in order. The elements after the range are shifted down to close the
+    /// gap.
+    ///
+    /// panics if the range is decreasing or out of bounds.
+    ///
+    /// note: all updates are performed as a single atomic operation.
+    ///       readers will see either the before or after state,
+    ///       never an intermediate state.
+    fn drain(&mut self, range: impl RangeBounds<Index>) -> std::vec::IntoIter<T>;
+
+    /// Replaces all elements of the collection with those of the snapshot.
+    ///
+    /// note: all updates are performed as a single atomic operation.
+    ///       readers will see either the before or after state,
+    ///       never an intermediate state.
+    fn restore_from_snapshot(&mut self, snapshot: StorageVecSnapshot<T>);
+
     /// get a mutable iterator over all elements
     ///
     /// note: all updates are performed as a single atomic operation.
@@ -1,6 +1,6 @@
 use super::level_db::DB;
 use super::storage_vec::Index;
-use serde::de::DeserializeOwned;
+use std::ops::{Bound, Range, RangeBounds};
 
 #[inline]
 pub(super) fn serialize<B>(b: &B) -> Vec<u8>
@@ -18,6 +18,16 @@ where
     bincode::deserialize(bytes).expect("should have deserialized value")
 }
 
+/// Like [`deserialize`], but returns `None` instead of panicking if the bytes do not encode a
+/// `B`.
+#[inline]
+pub(super) fn try_deserialize<'b, B>(bytes: &'b [u8]) -> Option<B>
+where
+    B: serde::de::Deserialize<'b>,
+{
+    bincode::deserialize(bytes).ok()
+}
+
 #[inline]
 pub(super) fn get_u8_option(db: &DB, index: &[u8], name: &str) -> Option<Vec<u8>> {
     db.get_u8(index).unwrap_or_else(|e| {
@@ -30,14 +40,27 @@ pub(super) fn get_u8_option(db: &DB, index: &[u8], name: &str) -> Option<Vec<u8>
     })
 }
 
-#[inline]
-pub(super) fn get_u8<T: DeserializeOwned>(db: &DB, index: &[u8], name: &str) -> T {
-    let db_val = get_u8_option(db, index, name).unwrap_or_else(|| {
-        panic!(
-            "Element with index {} does not exist in {}. This should not happen",
-            deserialize::<Index>(index),
-            name
-        )
-    });
-    deserialize(&db_val)
+/// Resolve `range` into the indices it denotes in a collection of length `len`.
+///
+/// panics if the range is decreasing or extends beyond `len`.
+pub(super) fn resolve_range(range: impl RangeBounds<Index>, len: Index) -> Range<Index> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("range start should not overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end should not overflow"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "Range start {start} exceeds range end {end}");
+    assert!(
+        end <= len,
+        "Out-of-bounds. Got range end {end} but length was {len}"
+    );
+    start..end
 }
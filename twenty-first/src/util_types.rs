@@ -1,6 +1,7 @@
 pub mod algebraic_hasher;
 pub mod blake3_wrapper;
 pub mod emojihash_trait;
+pub mod kary_merkle_tree;
 pub mod merkle_tree;
 pub mod merkle_tree_maker;
 pub mod mmr;
@@ -1,7 +1,9 @@
 use std::fmt::Debug;
 use std::iter;
+use std::marker::PhantomData;
 
 use itertools::Itertools;
+use rayon::prelude::*;
 
 use crate::shared_math::b_field_element::BFieldElement;
 use crate::shared_math::b_field_element::BFIELD_ONE;
@@ -58,12 +60,140 @@ pub trait Sponge: Clone + Debug + Default + Send + Sync {
             self.absorb(absorb_elems);
         }
     }
+
+    /// Absorb all full blocks of [`RATE`] elements of the input, and return the remaining, not yet
+    /// absorbed elements. The remainder is shorter than [`RATE`]; it is the caller's
+    /// responsibility to prepend it to the input of the next call to `absorb_slice` or
+    /// [`absorb_final`](Self::absorb_final). For automatic buffering, see [`SpongeWriter`].
+    fn absorb_slice<'a>(&mut self, input: &'a [BFieldElement]) -> &'a [BFieldElement] {
+        let mut chunks = input.chunks_exact(RATE);
+        for chunk in chunks.by_ref() {
+            self.absorb(chunk.try_into().unwrap());
+        }
+        chunks.remainder()
+    }
+
+    /// Absorb the input, applying the same padding as
+    /// [`pad_and_absorb_all`](Self::pad_and_absorb_all). Together with
+    /// [`absorb_slice`](Self::absorb_slice), the resulting state is the same as that of
+    /// `pad_and_absorb_all` on the entire input.
+    fn absorb_final(&mut self, input: &[BFieldElement]) {
+        let remainder = self.absorb_slice(input);
+        self.pad_and_absorb_all(remainder);
+    }
+}
+
+/// Incrementally absorbs input of arbitrary length into a [`Sponge`], buffering partial blocks of
+/// [`RATE`] elements. [Finalizing](Self::finalize) applies the same padding as
+/// [`pad_and_absorb_all`](Sponge::pad_and_absorb_all). Hence, the resulting digest is independent
+/// of how the input is split across calls to [`update`](Self::update), and for an
+/// [`AlgebraicHasher`], it equals [`hash_varlen`](AlgebraicHasher::hash_varlen) of the entire
+/// input.
+#[derive(Debug, Clone)]
+pub struct SpongeWriter<H: Sponge> {
+    sponge: H,
+    buffer: [BFieldElement; RATE],
+    buffer_len: usize,
+}
+
+impl<H: Sponge> Default for SpongeWriter<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Sponge> SpongeWriter<H> {
+    pub fn new() -> Self {
+        Self {
+            sponge: H::init(),
+            buffer: [BFIELD_ZERO; RATE],
+            buffer_len: 0,
+        }
+    }
+
+    /// Absorb all full blocks of [`RATE`] elements, buffering the rest.
+    pub fn update(&mut self, input: &[BFieldElement]) {
+        for &element in input {
+            self.buffer[self.buffer_len] = element;
+            self.buffer_len += 1;
+            if self.buffer_len == RATE {
+                self.sponge.absorb(self.buffer);
+                self.buffer_len = 0;
+            }
+        }
+    }
+
+    /// Pad and absorb the buffered elements, then squeeze once.
+    pub fn finalize(mut self) -> Digest {
+        self.sponge
+            .pad_and_absorb_all(&self.buffer[..self.buffer_len]);
+        let produce = self.sponge.squeeze();
+        Digest::new(produce[..DIGEST_LENGTH].try_into().unwrap())
+    }
+}
+
+/// Squeezes a [`Sponge`] at byte granularity, turning it into an extendable-output function.
+/// Every squeezed element is serialized to its 8 [canonical bytes][bytes]; bytes not yet
+/// requested are buffered for subsequent calls. Hence, the output is independent of how the
+/// requested number of bytes is split across calls to [`squeeze_bytes`](Self::squeeze_bytes).
+///
+/// Note that the most significant byte of every element's canonical representation is slightly
+/// biased, since the field's prime is smaller than 2^64.
+///
+/// [bytes]: BFieldElement::to_canonical_bytes
+#[derive(Debug, Clone)]
+pub struct SpongeReader<H: Sponge> {
+    sponge: H,
+    buffer: Vec<u8>,
+}
+
+impl<H: Sponge> SpongeReader<H> {
+    pub fn new(sponge: H) -> Self {
+        Self {
+            sponge,
+            buffer: vec![],
+        }
+    }
+
+    /// Return the next `num_bytes` bytes of output, squeezing the sponge as often as necessary.
+    pub fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        while self.buffer.len() < num_bytes {
+            let produce = self.sponge.squeeze();
+            let bytes = produce
+                .iter()
+                .flat_map(|element| element.to_canonical_bytes());
+            self.buffer.extend(bytes);
+        }
+        self.buffer.drain(..num_bytes).collect()
+    }
 }
 
 pub trait AlgebraicHasher: Sponge {
     /// 2-to-1 hashing
     fn hash_pair(left: Digest, right: Digest) -> Digest;
 
+    /// 2-to-1 hashing of many pairs at once. The result is identical to calling
+    /// [`hash_pair`](Self::hash_pair) on every pair, but implementations may process the batch
+    /// more efficiently.
+    fn hash_pair_batch(pairs: &[(Digest, Digest)]) -> Vec<Digest> {
+        pairs
+            .iter()
+            .map(|&(left, right)| Self::hash_pair(left, right))
+            .collect()
+    }
+
+    /// Many-to-1 hashing, for example of the children of a node in a
+    /// [k-ary Merkle tree](crate::util_types::kary_merkle_tree::KaryMerkleTree). Hashes the
+    /// concatenation of the digests using [`hash_varlen`](Self::hash_varlen). Consequently, the
+    /// result generally differs from [`hash_pair`](Self::hash_pair) for two digests.
+    fn hash_many(digests: &[Digest]) -> Digest {
+        let elements = digests
+            .iter()
+            .flat_map(|digest| digest.values())
+            .collect_vec();
+        Self::hash_varlen(&elements)
+    }
+
     /// Thin wrapper around [`hash_varlen`](Self::hash_varlen).
     fn hash<T: BFieldCodec>(value: &T) -> Digest {
         Self::hash_varlen(&value.encode())
@@ -82,6 +212,34 @@ pub trait AlgebraicHasher: Sponge {
         Digest::new((&produce[..DIGEST_LENGTH]).try_into().unwrap())
     }
 
+    /// Hash many variable-length sequences of [`BFieldElement`]s in parallel. The result is
+    /// identical to calling [`hash_varlen`](Self::hash_varlen) on every input.
+    fn hash_varlen_batch(inputs: &[Vec<BFieldElement>]) -> Vec<Digest> {
+        inputs
+            .par_iter()
+            .map(|input| Self::hash_varlen(input))
+            .collect()
+    }
+
+    /// Hash a sequence of bytes.
+    ///
+    /// The bytes are packed into [`BFieldElement`]s unambiguously: the first element is the
+    /// number of bytes, followed by the bytes in chunks of 7, each interpreted as a little-endian
+    /// integer. The last chunk is padded with zeros. Since the length is part of the input, byte
+    /// strings differing only in trailing zeros hash differently.
+    fn hash_bytes(data: &[u8]) -> Digest {
+        const BYTES_PER_ELEMENT: usize = 7;
+
+        let length = BFieldElement::new(data.len() as u64);
+        let packed_bytes = data.chunks(BYTES_PER_ELEMENT).map(|chunk| {
+            let mut bytes = [0; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            BFieldElement::new(u64::from_le_bytes(bytes))
+        });
+        let elements = iter::once(length).chain(packed_bytes).collect_vec();
+        Self::hash_varlen(&elements)
+    }
+
     /// Produce `num_indices` random integer values in the range `[0, upper_bound)`. The
     /// `upper_bound` must be a power of 2.
     ///
@@ -129,6 +287,33 @@ pub trait AlgebraicHasher: Sponge {
     }
 }
 
+/// An object-safe counterpart of [`AlgebraicHasher`], allowing to select the hash function at
+/// runtime, for example, through `Arc<dyn DynHasher>`. Any `AlgebraicHasher` can be turned into a
+/// `DynHasher` using [`StaticHasher`].
+///
+/// Dynamic dispatch prevents some compiler optimizations. Prefer [`AlgebraicHasher`] where the
+/// hash function is known at compile time.
+pub trait DynHasher: Debug + Send + Sync {
+    /// 2-to-1 hashing
+    fn hash_pair(&self, left: &Digest, right: &Digest) -> Digest;
+}
+
+/// Adapter making an [`AlgebraicHasher`] usable as a [`DynHasher`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StaticHasher<H: AlgebraicHasher>(PhantomData<H>);
+
+impl<H: AlgebraicHasher> StaticHasher<H> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<H: AlgebraicHasher> DynHasher for StaticHasher<H> {
+    fn hash_pair(&self, left: &Digest, right: &Digest) -> Digest {
+        H::hash_pair(*left, *right)
+    }
+}
+
 #[cfg(test)]
 mod algebraic_hasher_tests {
     use std::ops::Mul;
@@ -220,6 +405,133 @@ mod algebraic_hasher_tests {
         }
     }
 
+    #[test]
+    fn hash_varlen_batch_agrees_with_hash_varlen() {
+        let mut rng = rand::thread_rng();
+        let inputs = (0..100)
+            .map(|i| (0..i % 25).map(|_| rng.gen()).collect_vec())
+            .collect_vec();
+        let batch_digests = Tip5::hash_varlen_batch(&inputs);
+        let digests = inputs
+            .iter()
+            .map(|input| Tip5::hash_varlen(input))
+            .collect_vec();
+        assert_eq!(digests, batch_digests);
+        assert!(Tip5::hash_varlen_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn hash_pair_batch_agrees_with_hash_pair() {
+        let mut rng = rand::thread_rng();
+        for num_pairs in [0, 1, 2, 10, 100] {
+            let pairs: Vec<(Digest, Digest)> = (0..num_pairs).map(|_| rng.gen()).collect();
+            let batch_digests = Tip5::hash_pair_batch(&pairs);
+            let digests = pairs
+                .into_iter()
+                .map(|(left, right)| Tip5::hash_pair(left, right))
+                .collect_vec();
+            assert_eq!(digests, batch_digests);
+        }
+    }
+
+    #[test]
+    fn hash_bytes_distinguishes_trailing_zeros_and_lengths() {
+        let empty_digest = Tip5::hash_bytes(&[]);
+        assert_eq!(empty_digest, Tip5::hash_bytes(&[]));
+        assert_ne!(empty_digest, Tip5::hash_bytes(&[0]));
+
+        let data = b"twenty-first".to_vec();
+        let digest = Tip5::hash_bytes(&data);
+        for num_trailing_zeros in 1..=8 {
+            let mut padded_data = data.clone();
+            padded_data.resize(data.len() + num_trailing_zeros, 0);
+            assert_ne!(digest, Tip5::hash_bytes(&padded_data));
+        }
+        for length in 0..data.len() {
+            assert_ne!(digest, Tip5::hash_bytes(&data[..length]));
+        }
+    }
+
+    #[test]
+    fn hash_bytes_does_not_collide_with_hash_varlen_of_packed_bytes() {
+        let data = [1, 2, 3];
+        let packed_bytes = [BFieldElement::new(0x030201)];
+        assert_ne!(Tip5::hash_varlen(&packed_bytes), Tip5::hash_bytes(&data));
+    }
+
+    #[test]
+    fn sponge_writer_is_independent_of_chunk_sizes() {
+        let mut rng = rand::thread_rng();
+        for input_length in [0, 1, 9, 10, 11, 20, 57, 100] {
+            let input: Vec<BFieldElement> = (0..input_length).map(|_| rng.gen()).collect();
+            let expected = Tip5::hash_varlen(&input);
+
+            for max_chunk_size in [1, 3, 10, 11, 64] {
+                let mut writer = SpongeWriter::<Tip5>::new();
+                let mut remaining_input = &input[..];
+                while !remaining_input.is_empty() {
+                    let chunk_size = rng.gen_range(0..=max_chunk_size);
+                    let chunk_size = chunk_size.min(remaining_input.len());
+                    let (chunk, rest) = remaining_input.split_at(chunk_size);
+                    writer.update(chunk);
+                    remaining_input = rest;
+                }
+                assert_eq!(expected, writer.finalize());
+            }
+        }
+    }
+
+    #[test]
+    fn absorbing_slices_then_final_input_agrees_with_hash_varlen() {
+        let mut rng = rand::thread_rng();
+        for input_length in [0, 1, 9, 10, 11, 20, 23, 57, 100] {
+            let input: Vec<BFieldElement> = (0..input_length).map(|_| rng.gen()).collect();
+            let expected = Tip5::hash_varlen(&input);
+
+            for split_index in 0..=input_length {
+                let (first_part, second_part) = input.split_at(split_index);
+                let mut sponge = Tip5::init();
+                let remainder = sponge.absorb_slice(first_part);
+                assert!(remainder.len() < RATE);
+
+                let final_input = [remainder, second_part].concat();
+                sponge.absorb_final(&final_input);
+                let produce = sponge.squeeze();
+                let digest = Digest::new(produce[..DIGEST_LENGTH].try_into().unwrap());
+                assert_eq!(expected, digest);
+            }
+        }
+    }
+
+    #[test]
+    fn sponge_reader_is_independent_of_chunk_sizes() {
+        let mut rng = rand::thread_rng();
+        let sponge = Tip5::randomly_seeded();
+        for num_bytes in [0, 1, 7, 8, 80, 81, 500] {
+            let expected = SpongeReader::new(sponge.clone()).squeeze_bytes(num_bytes);
+            assert_eq!(num_bytes, expected.len());
+
+            for max_chunk_size in [1, 3, 8, 79, 100] {
+                let mut reader = SpongeReader::new(sponge.clone());
+                let mut output = vec![];
+                while output.len() < num_bytes {
+                    let chunk_size = rng.gen_range(0..=max_chunk_size);
+                    let chunk_size = chunk_size.min(num_bytes - output.len());
+                    output.extend(reader.squeeze_bytes(chunk_size));
+                }
+                assert_eq!(expected, output);
+            }
+        }
+    }
+
+    #[test]
+    fn sponge_reader_output_consists_of_canonical_bytes_of_squeezed_elements() {
+        let mut sponge = Tip5::randomly_seeded();
+        let mut reader = SpongeReader::new(sponge.clone());
+        let expected = sponge.squeeze().map(|element| element.to_canonical_bytes());
+        assert_eq!(expected.concat(), reader.squeeze_bytes(8 * RATE));
+    }
+
     #[test]
     fn sample_scalars_test() {
         let amounts = [0, 1, 2, 3, 4];
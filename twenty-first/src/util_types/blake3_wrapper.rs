@@ -7,11 +7,23 @@ use crate::util_types::algebraic_hasher::AlgebraicHasher;
 use crate::util_types::algebraic_hasher::Sponge;
 use crate::util_types::algebraic_hasher::RATE;
 
+/// Domain separation for the [`Sponge`] of [`blake3::Hasher`], used for
+/// [`hash_varlen`](AlgebraicHasher::hash_varlen) and thus for hashing leaves.
+pub const SPONGE_CONTEXT: &str = "twenty-first blake3 AlgebraicHasher sponge";
+
+/// Domain separation for [`hash_pair`](AlgebraicHasher::hash_pair) of [`blake3::Hasher`],
+/// used for hashing internal nodes of Merkle trees.
+pub const HASH_PAIR_CONTEXT: &str = "twenty-first blake3 AlgebraicHasher hash_pair";
+
+/// The sponge and [`hash_pair`](AlgebraicHasher::hash_pair) use blake3 in key derivation mode
+/// with distinct contexts, [`SPONGE_CONTEXT`] and [`HASH_PAIR_CONTEXT`]. This way, hashing two
+/// digests as an internal node can never coincide with hashing their concatenation as a leaf,
+/// independent of the shape of the tree.
 impl Sponge for blake3::Hasher {
     const RATE: usize = RATE;
 
     fn init() -> Self {
-        blake3::Hasher::new()
+        blake3::Hasher::new_derive_key(SPONGE_CONTEXT)
     }
 
     fn absorb(&mut self, input: [BFieldElement; RATE]) {
@@ -38,7 +50,7 @@ impl Sponge for blake3::Hasher {
 
 impl AlgebraicHasher for blake3::Hasher {
     fn hash_pair(left: Digest, right: Digest) -> Digest {
-        let mut hasher = blake3::Hasher::new();
+        let mut hasher = blake3::Hasher::new_derive_key(HASH_PAIR_CONTEXT);
         for elem in left.values().iter().chain(right.values().iter()) {
             hasher.update(&elem.value().to_be_bytes());
         }
@@ -57,3 +69,32 @@ pub fn from_blake3_digest(digest: &blake3::Hash) -> Digest {
     ];
     Digest::new(elements)
 }
+
+#[cfg(test)]
+mod blake3_wrapper_tests {
+    use proptest::prelude::*;
+    use test_strategy::proptest;
+
+    use super::*;
+
+    #[proptest]
+    fn hash_pair_differs_from_hashing_concatenation(left: Digest, right: Digest) {
+        let concatenation = [left.values(), right.values()].concat();
+        let pair_digest = blake3::Hasher::hash_pair(left, right);
+        prop_assert_ne!(blake3::Hasher::hash_varlen(&concatenation), pair_digest);
+
+        let mut sponge = blake3::Hasher::init();
+        sponge.absorb(concatenation.try_into().unwrap());
+        prop_assert_ne!(from_blake3_digest(&sponge.finalize()), pair_digest);
+    }
+
+    #[proptest]
+    fn hash_pair_is_domain_separated_from_plain_blake3(left: Digest, right: Digest) {
+        let mut hasher = blake3::Hasher::new();
+        for elem in left.values().iter().chain(right.values().iter()) {
+            hasher.update(&elem.value().to_be_bytes());
+        }
+        let plain_digest = from_blake3_digest(&hasher.finalize());
+        prop_assert_ne!(plain_digest, blake3::Hasher::hash_pair(left, right));
+    }
+}
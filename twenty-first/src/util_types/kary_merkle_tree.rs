@@ -0,0 +1,484 @@
+use std::collections::hash_map::Entry::*;
+use std::collections::*;
+use std::marker::PhantomData;
+use std::result;
+
+use itertools::Itertools;
+
+use crate::shared_math::digest::Digest;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::merkle_tree::MerkleTreeError;
+use crate::util_types::merkle_tree::MAX_NUM_LEAVES;
+
+/// The index of the root node in a [k-ary Merkle tree](KaryMerkleTree). Note that this differs
+/// from the [root index of a binary Merkle tree](crate::util_types::merkle_tree::ROOT_INDEX).
+const ROOT_INDEX: usize = 0;
+
+type Result<T> = result::Result<T, MerkleTreeError>;
+
+/// A [Merkle tree](crate::util_types::merkle_tree::MerkleTree) in which every internal node has
+/// `ARITY` children, the digest of which is computed using
+/// [`hash_many`](AlgebraicHasher::hash_many). Compared to a binary Merkle tree, the tree is
+/// shallower, making authentication paths shorter in terms of number of layers.
+///
+/// If the number of leaves is not a power of `ARITY`, the leaves are padded with
+/// [default digests](Digest::default) up to the next power of `ARITY`. The root does not commit
+/// to the number of leaves: trees over leaves that differ only in trailing default digests have
+/// the same root. If the number of leaves matters, it must be authenticated separately.
+///
+/// The nodes are stored level by level, starting with the root at index 0. The children of the
+/// node with index `i` have indices `ARITY·i + 1` through `ARITY·i + ARITY`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KaryMerkleTree<H, const ARITY: usize>
+where
+    H: AlgebraicHasher,
+{
+    nodes: Vec<Digest>,
+    num_leaves: usize,
+    _hasher: PhantomData<H>,
+}
+
+/// A full inclusion proof for the leaves at the supplied indices relative to some
+/// [k-ary Merkle tree](KaryMerkleTree), including the leaves themselves. The k-ary analogue of
+/// [`MerkleTreeInclusionProof`](crate::util_types::merkle_tree::MerkleTreeInclusionProof).
+///
+/// Since the [root](KaryMerkleTree::root) does not commit to the number of leaves, the proof does
+/// not state it. Instead, the verifier must [supply](Self::verify) a number of leaves it trusts.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KaryMerkleTreeInclusionProof<H, const ARITY: usize>
+where
+    H: AlgebraicHasher,
+{
+    /// The stated height of the Merkle tree this proof is relative to.
+    pub tree_height: usize,
+
+    /// The leaves the proof is about, _i.e._, the revealed leaves.
+    pub indexed_leaves: Vec<(usize, Digest)>,
+
+    /// The proof's witness: de-duplicated authentication structure for the leaves this proof is
+    /// about. See [`authentication_structure`](KaryMerkleTree::authentication_structure).
+    pub authentication_structure: Vec<Digest>,
+
+    pub _hasher: PhantomData<H>,
+}
+
+impl<H, const ARITY: usize> KaryMerkleTree<H, ARITY>
+where
+    H: AlgebraicHasher,
+{
+    /// Build a k-ary Merkle tree over the given leaves, padding them if necessary.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of leaves is 0.
+    /// - If the padded number of leaves is too large.
+    ///
+    /// # Panics
+    ///
+    /// If `ARITY` is less than 2.
+    pub fn new(leaves: &[Digest]) -> Result<Self> {
+        assert!(ARITY >= 2, "The arity of a Merkle tree must be at least 2.");
+        if leaves.is_empty() {
+            return Err(MerkleTreeError::TooFewLeaves);
+        }
+
+        let mut tree_height = 0;
+        while Self::num_padded_leaves(tree_height)? < leaves.len() {
+            tree_height += 1;
+        }
+        let num_padded_leaves = Self::num_padded_leaves(tree_height)?;
+        let first_leaf_index = Self::first_leaf_node_index(num_padded_leaves);
+
+        let mut nodes = vec![Digest::default(); first_leaf_index + num_padded_leaves];
+        nodes[first_leaf_index..first_leaf_index + leaves.len()].copy_from_slice(leaves);
+        for node_index in (0..first_leaf_index).rev() {
+            let first_child_index = Self::first_child_node_index(node_index);
+            let children = &nodes[first_child_index..first_child_index + ARITY];
+            nodes[node_index] = H::hash_many(children);
+        }
+
+        let tree = Self {
+            nodes,
+            num_leaves: leaves.len(),
+            _hasher: PhantomData,
+        };
+        Ok(tree)
+    }
+
+    fn num_padded_leaves(tree_height: usize) -> Result<usize> {
+        u32::try_from(tree_height)
+            .ok()
+            .and_then(|height| ARITY.checked_pow(height))
+            .filter(|&num_leaves| num_leaves <= MAX_NUM_LEAVES)
+            .ok_or(MerkleTreeError::TreeTooHigh)
+    }
+
+    fn first_leaf_node_index(num_padded_leaves: usize) -> usize {
+        (num_padded_leaves - 1) / (ARITY - 1)
+    }
+
+    fn first_child_node_index(node_index: usize) -> usize {
+        ARITY * node_index + 1
+    }
+
+    fn parent_node_index(node_index: usize) -> usize {
+        (node_index - 1) / ARITY
+    }
+
+    /// Given a list of leaf indices, return the indices of exactly those nodes that are needed to
+    /// prove (or verify) that the indicated leaves are in the Merkle tree of the given height.
+    /// The returned indices are sorted in descending order.
+    fn authentication_structure_node_indices(
+        tree_height: usize,
+        leaf_indices: &[usize],
+    ) -> Result<Vec<usize>> {
+        let num_leaves = Self::num_padded_leaves(tree_height)?;
+        let first_leaf_index = Self::first_leaf_node_index(num_leaves);
+
+        let mut node_is_needed = HashSet::new();
+        let mut node_can_be_computed = HashSet::new();
+        for &leaf_index in leaf_indices {
+            if leaf_index >= num_leaves {
+                return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
+            }
+
+            let mut node_index = leaf_index + first_leaf_index;
+            while node_index != ROOT_INDEX {
+                node_can_be_computed.insert(node_index);
+                let parent_index = Self::parent_node_index(node_index);
+                let first_sibling_index = Self::first_child_node_index(parent_index);
+                let siblings = first_sibling_index..first_sibling_index + ARITY;
+                node_is_needed.extend(siblings.filter(|&i| i != node_index));
+                node_index = parent_index;
+            }
+        }
+
+        let set_difference = node_is_needed.difference(&node_can_be_computed).copied();
+        Ok(set_difference.sorted_unstable().rev().collect())
+    }
+
+    /// Generate a de-duplicated authentication structure for the given leaf indices. If a single
+    /// index is supplied, the authentication structure consists of `ARITY - 1` siblings per
+    /// layer of the tree. See also
+    /// [`MerkleTree::authentication_structure`](crate::util_types::merkle_tree::MerkleTree::authentication_structure).
+    pub fn authentication_structure(&self, leaf_indices: &[usize]) -> Result<Vec<Digest>> {
+        let num_leaves = self.num_leaves;
+        if leaf_indices.iter().any(|&i| i >= num_leaves) {
+            return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
+        }
+
+        let indices = Self::authentication_structure_node_indices(self.height(), leaf_indices)?;
+        let auth_structure = indices.into_iter().map(|i| self.nodes[i]).collect();
+        Ok(auth_structure)
+    }
+
+    pub fn root(&self) -> Digest {
+        self.nodes[ROOT_INDEX]
+    }
+
+    /// The number of leaves the tree was built from, excluding padding.
+    pub fn num_leafs(&self) -> usize {
+        self.num_leaves
+    }
+
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut node_index = self.nodes.len() - 1;
+        while node_index != ROOT_INDEX {
+            node_index = Self::parent_node_index(node_index);
+            height += 1;
+        }
+        height
+    }
+
+    /// The leaf at the given index, if it exists. Padding is not considered a leaf.
+    pub fn leaf(&self, index: usize) -> Option<Digest> {
+        if index >= self.num_leaves {
+            return None;
+        }
+        let num_padded_leaves = Self::num_padded_leaves(self.height()).ok()?;
+        let first_leaf_index = Self::first_leaf_node_index(num_padded_leaves);
+        self.nodes.get(first_leaf_index + index).copied()
+    }
+
+    pub fn indexed_leaves(&self, indices: &[usize]) -> Result<Vec<(usize, Digest)>> {
+        let num_leaves = self.num_leaves;
+        let invalid_index = MerkleTreeError::LeafIndexInvalid { num_leaves };
+        let maybe_indexed_leaf = |&i| self.leaf(i).ok_or(invalid_index).map(|leaf| (i, leaf));
+
+        indices.iter().map(maybe_indexed_leaf).collect()
+    }
+
+    /// A full inclusion proof for the leaves at the supplied indices, including the leaves.
+    pub fn inclusion_proof_for_leaf_indices(
+        &self,
+        indices: &[usize],
+    ) -> Result<KaryMerkleTreeInclusionProof<H, ARITY>> {
+        let proof = KaryMerkleTreeInclusionProof {
+            tree_height: self.height(),
+            indexed_leaves: self.indexed_leaves(indices)?,
+            authentication_structure: self.authentication_structure(indices)?,
+            _hasher: PhantomData,
+        };
+        Ok(proof)
+    }
+}
+
+impl<H, const ARITY: usize> KaryMerkleTreeInclusionProof<H, ARITY>
+where
+    H: AlgebraicHasher,
+{
+    fn is_trivial(&self) -> bool {
+        self.indexed_leaves.is_empty() && self.authentication_structure.is_empty()
+    }
+
+    /// Verify that the given root digest is the root of a k-ary Merkle tree with `num_leaves`
+    /// leaves, excluding padding, that contains the indicated leaves.
+    ///
+    /// The root does not commit to `num_leaves`, which must come from a trusted source. Leaf
+    /// indices in the padding region, _i.e._, indices not less than `num_leaves`, are rejected.
+    pub fn verify(self, expected_root: Digest, num_leaves: usize) -> bool {
+        if self.is_trivial() {
+            return true;
+        }
+        let Ok(computed_root) = self.compute_root(num_leaves) else {
+            return false;
+        };
+        computed_root == expected_root
+    }
+
+    fn compute_root(self, num_leaves: usize) -> Result<Digest> {
+        let num_padded_leaves = KaryMerkleTree::<H, ARITY>::num_padded_leaves(self.tree_height)?;
+        let first_leaf_index = KaryMerkleTree::<H, ARITY>::first_leaf_node_index(num_padded_leaves);
+        let leaf_indices = self.indexed_leaves.iter().map(|&(i, _)| i).collect_vec();
+        if num_leaves > num_padded_leaves || leaf_indices.iter().any(|&i| i >= num_leaves) {
+            return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
+        }
+        let node_indices = KaryMerkleTree::<H, ARITY>::authentication_structure_node_indices(
+            self.tree_height,
+            &leaf_indices,
+        )?;
+        if self.authentication_structure.len() != node_indices.len() {
+            return Err(MerkleTreeError::AuthenticationStructureLengthMismatch);
+        }
+
+        let mut nodes: HashMap<_, _> = node_indices
+            .into_iter()
+            .zip_eq(self.authentication_structure)
+            .collect();
+        for (leaf_index, leaf_digest) in self.indexed_leaves {
+            let node_index = leaf_index + first_leaf_index;
+            if let Vacant(entry) = nodes.entry(node_index) {
+                entry.insert(leaf_digest);
+            } else if nodes[&node_index] != leaf_digest {
+                return Err(MerkleTreeError::RepeatedLeafDigestMismatch);
+            }
+        }
+
+        let mut layer_node_indices = leaf_indices
+            .into_iter()
+            .map(|i| i + first_leaf_index)
+            .sorted_unstable()
+            .dedup()
+            .collect_vec();
+        for _ in 0..self.tree_height {
+            let parent_node_indices = layer_node_indices
+                .into_iter()
+                .map(KaryMerkleTree::<H, ARITY>::parent_node_index)
+                .dedup()
+                .collect_vec();
+            for &parent_index in &parent_node_indices {
+                let first_child_index =
+                    KaryMerkleTree::<H, ARITY>::first_child_node_index(parent_index);
+                let children = (first_child_index..first_child_index + ARITY)
+                    .map(|i| {
+                        nodes
+                            .get(&i)
+                            .copied()
+                            .ok_or(MerkleTreeError::MissingNodeIndex(i))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                nodes.insert(parent_index, H::hash_many(&children));
+            }
+            layer_node_indices = parent_node_indices;
+        }
+
+        nodes
+            .get(&ROOT_INDEX)
+            .copied()
+            .ok_or(MerkleTreeError::RootNotFound)
+    }
+}
+
+#[cfg(test)]
+mod kary_merkle_tree_test {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::shared_math::digest::digest_tests::DigestCorruptor;
+    use crate::shared_math::tip5::Tip5;
+
+    use super::*;
+
+    type QuaternaryTree = KaryMerkleTree<Tip5, 4>;
+    type TernaryTree = KaryMerkleTree<Tip5, 3>;
+
+    #[test]
+    fn building_tree_from_no_leaves_fails() {
+        let err = QuaternaryTree::new(&[]).unwrap_err();
+        assert_eq!(MerkleTreeError::TooFewLeaves, err);
+    }
+
+    #[proptest]
+    fn tree_of_one_leaf_has_that_leaf_as_root(leaf: Digest) {
+        let tree = QuaternaryTree::new(&[leaf]).unwrap();
+        prop_assert_eq!(0, tree.height());
+        prop_assert_eq!(leaf, tree.root());
+    }
+
+    #[test]
+    fn quaternary_tree_of_256_leaves_has_height_4() {
+        let leaves = (0..256).map(Digest::from_seed).collect_vec();
+        let tree = QuaternaryTree::new(&leaves).unwrap();
+        assert_eq!(4, tree.height());
+        assert_eq!(256, tree.num_leafs());
+
+        let authentication_path = tree.authentication_structure(&[42]).unwrap();
+        assert_eq!(4 * 3, authentication_path.len());
+    }
+
+    #[proptest(cases = 30)]
+    fn padding_leaves_is_deterministic_and_matches_explicit_padding(
+        #[strategy(vec(arb(), 1..=100))] leaves: Vec<Digest>,
+    ) {
+        let tree = TernaryTree::new(&leaves).unwrap();
+        let num_padded_leaves = 3_usize.pow(tree.height() as u32);
+        prop_assert!(leaves.len() <= num_padded_leaves);
+        prop_assert!(leaves.len() > num_padded_leaves / 3 || tree.height() == 0);
+
+        let mut padded_leaves = leaves.clone();
+        padded_leaves.resize(num_padded_leaves, Digest::default());
+        let padded_tree = TernaryTree::new(&padded_leaves).unwrap();
+        prop_assert_eq!(tree.root(), padded_tree.root());
+        prop_assert_eq!(tree.root(), TernaryTree::new(&leaves).unwrap().root());
+    }
+
+    #[proptest(cases = 30)]
+    fn inclusion_proof_of_arbitrary_leaves_can_be_verified(
+        #[strategy(vec(0_usize..256, 0..=30))] leaf_indices: Vec<usize>,
+    ) {
+        let leaves = (0..256).map(Digest::from_seed).collect_vec();
+        let tree = QuaternaryTree::new(&leaves).unwrap();
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        prop_assert!(proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[proptest(cases = 30)]
+    fn inclusion_proof_of_padded_tree_can_be_verified(
+        #[strategy(vec(arb(), 1..=100))] leaves: Vec<Digest>,
+        #[strategy(vec(0..#leaves.len(), 1..=#leaves.len()))] leaf_indices: Vec<usize>,
+    ) {
+        let tree = TernaryTree::new(&leaves).unwrap();
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        prop_assert!(proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[proptest(cases = 30)]
+    fn corrupt_leaf_leads_to_verification_failure(
+        #[strategy(vec(0_usize..256, 1..=30))] leaf_indices: Vec<usize>,
+        #[strategy(0..#leaf_indices.len())] index_to_corrupt: usize,
+        corruptor: DigestCorruptor,
+    ) {
+        let leaves = (0..256).map(Digest::from_seed).collect_vec();
+        let tree = QuaternaryTree::new(&leaves).unwrap();
+        let mut proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        let (_, leaf) = &mut proof.indexed_leaves[index_to_corrupt];
+        *leaf = corruptor.corrupt_digest(*leaf)?;
+        prop_assert!(!proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[proptest(cases = 30)]
+    fn corrupt_authentication_structure_leads_to_verification_failure(
+        #[strategy(vec(0_usize..256, 1..=30))] leaf_indices: Vec<usize>,
+        index_to_corrupt: prop::sample::Index,
+        corruptor: DigestCorruptor,
+    ) {
+        let leaves = (0..256).map(Digest::from_seed).collect_vec();
+        let tree = QuaternaryTree::new(&leaves).unwrap();
+        let mut proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        let index_to_corrupt = index_to_corrupt.index(proof.authentication_structure.len());
+        let node = &mut proof.authentication_structure[index_to_corrupt];
+        *node = corruptor.corrupt_digest(*node)?;
+        prop_assert!(!proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[proptest(cases = 30)]
+    fn incorrect_tree_height_leads_to_verification_failure(
+        #[strategy(vec(0_usize..256, 1..=30))] leaf_indices: Vec<usize>,
+        #[strategy(0_usize..10)]
+        #[filter(#incorrect_height != 4)]
+        incorrect_height: usize,
+    ) {
+        let leaves = (0..256).map(Digest::from_seed).collect_vec();
+        let tree = QuaternaryTree::new(&leaves).unwrap();
+        let mut proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        proof.tree_height = incorrect_height;
+        prop_assert!(!proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[test]
+    fn verifying_inclusion_of_padding_fails() {
+        let leaves = (0..10).map(Digest::from_seed).collect_vec();
+        let tree = TernaryTree::new(&leaves).unwrap();
+        let mut proof = tree.inclusion_proof_for_leaf_indices(&[9]).unwrap();
+        assert!(proof.clone().verify(tree.root(), tree.num_leafs()));
+
+        // leaf 10 lies in the padding region, where every leaf is the default digest
+        proof.indexed_leaves = vec![(9, leaves[9]), (10, Digest::default())];
+        let node_indices =
+            TernaryTree::authentication_structure_node_indices(tree.height(), &[9, 10]).unwrap();
+        proof.authentication_structure = node_indices.into_iter().map(|i| tree.nodes[i]).collect();
+        assert!(!proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[test]
+    fn forged_proof_of_padding_fails_against_trusted_number_of_leaves() {
+        let leaves = (0..10).map(Digest::from_seed).collect_vec();
+        let tree = TernaryTree::new(&leaves).unwrap();
+
+        // A forger makes the padding explicit, claiming a tree of 27 leaves with the same root.
+        let mut forged_leaves = leaves.clone();
+        forged_leaves.resize(27, Digest::default());
+        let forged_tree = TernaryTree::new(&forged_leaves).unwrap();
+        assert_eq!(tree.root(), forged_tree.root());
+
+        let forged_proof = forged_tree
+            .inclusion_proof_for_leaf_indices(&[9, 10])
+            .unwrap();
+        assert!(forged_proof
+            .clone()
+            .verify(tree.root(), forged_tree.num_leafs()));
+        assert!(!forged_proof.verify(tree.root(), tree.num_leafs()));
+    }
+
+    #[test]
+    fn requesting_authentication_structure_for_padding_fails() {
+        let leaves = (0..10).map(Digest::from_seed).collect_vec();
+        let tree = TernaryTree::new(&leaves).unwrap();
+        let err = tree.authentication_structure(&[10]).unwrap_err();
+        assert_eq!(MerkleTreeError::LeafIndexInvalid { num_leaves: 10 }, err);
+        assert_eq!(None, tree.leaf(10));
+    }
+}
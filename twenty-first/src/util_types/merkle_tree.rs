@@ -2,8 +2,12 @@ use std::collections::hash_map::Entry::*;
 use std::collections::*;
 use std::env;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::result;
+use std::sync::Arc;
 
 use arbitrary::*;
 use itertools::Itertools;
@@ -13,7 +17,9 @@ use thiserror::Error;
 
 use crate::shared_math::digest::Digest;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::algebraic_hasher::DynHasher;
 use crate::util_types::merkle_tree_maker::MerkleTreeMaker;
+use crate::util_types::shared::bag_peaks;
 
 const DEFAULT_PARALLELIZATION_CUTOFF: usize = 256;
 lazy_static! {
@@ -24,7 +30,7 @@ lazy_static! {
 }
 
 const MAX_NUM_NODES: usize = 1 << 32;
-const MAX_NUM_LEAVES: usize = MAX_NUM_NODES / 2;
+pub(crate) const MAX_NUM_LEAVES: usize = MAX_NUM_NODES / 2;
 pub const MAX_TREE_HEIGHT: usize = MAX_NUM_LEAVES.ilog2() as usize;
 
 /// The index of the root node in a [Merkle tree](MerkleTree).
@@ -33,11 +39,27 @@ pub const ROOT_INDEX: usize = 1;
 
 type Result<T> = result::Result<T, MerkleTreeError>;
 
+/// The node indices of the authentication path of the indicated leaf in a tree with `num_leaves`
+/// leaves, ordered from the leaf's sibling to the root's child.
+fn authentication_path_node_indices(
+    num_leaves: usize,
+    leaf_index: usize,
+) -> impl Iterator<Item = usize> {
+    let leaf_node_index = leaf_index + num_leaves;
+    std::iter::successors(Some(leaf_node_index), |&node_index| Some(node_index / 2))
+        .take_while(|&node_index| node_index > ROOT_INDEX)
+        .map(|node_index| node_index ^ 1)
+}
+
 /// A [Merkle tree][merkle_tree] is a binary tree of [digests](Digest) that is used to efficiently prove the
 /// inclusion of items in a set. Set inclusion can be verified through an [inclusion proof](MerkleTreeInclusionProof).
 ///
+/// Two Merkle trees are considered equal if their roots are equal, making comparison and
+/// [hashing](Hash) cheap. Barring (deliberately found) hash collisions, equal roots imply equal
+/// trees.
+///
 /// [merkle_tree]: https://en.wikipedia.org/wiki/Merkle_tree
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct MerkleTree<H>
 where
     H: AlgebraicHasher,
@@ -140,6 +162,21 @@ where
         Ok(set_difference.sorted_unstable().rev())
     }
 
+    /// The length of the [authentication structure](Self::authentication_structure) for the
+    /// given leaf indices in a tree of the given height. Does not require access to the tree,
+    /// allowing verifiers to validate the size of a proof before receiving it.
+    pub fn authentication_structure_length(
+        tree_height: usize,
+        leaf_indices: &[usize],
+    ) -> Result<usize> {
+        if tree_height > MAX_TREE_HEIGHT {
+            return Err(MerkleTreeError::TreeTooHigh);
+        }
+        let num_leaves = 1 << tree_height;
+        let node_indices = Self::authentication_structure_node_indices(num_leaves, leaf_indices)?;
+        Ok(node_indices.len())
+    }
+
     /// Generate a de-duplicated authentication structure for the given leaf indices.
     /// If a single index is supplied, the authentication structure is the authentication path for the indicated leaf.
     ///
@@ -178,6 +215,18 @@ where
         Ok(auth_structure)
     }
 
+    /// The individual authentication path of every leaf, in order of the leaf indices. The path
+    /// of leaf `i` equals the [authentication structure](Self::authentication_structure) for the
+    /// single leaf `i`. Paths are computed lazily, directly from the tree's nodes.
+    pub fn all_authentication_paths(&self) -> impl Iterator<Item = Vec<Digest>> + '_ {
+        let num_leafs = self.num_leafs();
+        (0..num_leafs).map(move |leaf_index| {
+            authentication_path_node_indices(num_leafs, leaf_index)
+                .map(|node_index| self.nodes[node_index])
+                .collect()
+        })
+    }
+
     pub fn root(&self) -> Digest {
         self.nodes[ROOT_INDEX]
     }
@@ -199,8 +248,18 @@ where
         &self.nodes
     }
 
-    /// The node at the given node index, if it exists.
+    /// The number of nodes in the Merkle tree, _i.e._, `2·num_leafs - 1`. Valid node indices
+    /// range from [`ROOT_INDEX`] up to and including this number.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    /// The node at the given node index, if it exists. Node index 0 does not correspond to any
+    /// node of the tree.
     pub fn node(&self, index: usize) -> Option<Digest> {
+        if index < ROOT_INDEX {
+            return None;
+        }
         self.nodes.get(index).copied()
     }
 
@@ -216,6 +275,47 @@ where
         self.nodes.get(first_leaf_index + index).copied()
     }
 
+    /// The leaves in the given range of leaf indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or extends beyond the last leaf.
+    pub fn leaves_in_range(&self, range: Range<usize>) -> Vec<Digest> {
+        let num_leaves = self.num_leafs();
+        assert!(
+            range.start <= range.end,
+            "Leaf range must not be decreasing, but was {range:?}."
+        );
+        assert!(
+            range.end <= num_leaves,
+            "Leaf range {range:?} exceeds the number of leaves, {num_leaves}."
+        );
+
+        let first_leaf_index = self.nodes.len() / 2;
+        let start = first_leaf_index + range.start;
+        let end = first_leaf_index + range.end;
+        self.nodes[start..end].to_vec()
+    }
+
+    /// The roots of all subtrees of the given height, from left to right. These are the nodes
+    /// on layer `tree_height - subtree_height`, counting from the root. The `i`th subtree root
+    /// equals the root of the Merkle tree built from the `i`th block of `2^subtree_height`
+    /// leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the subtree height exceeds the tree's height.
+    pub fn subtree_roots(&self, subtree_height: usize) -> Vec<Digest> {
+        let tree_height = self.height();
+        assert!(
+            subtree_height <= tree_height,
+            "Subtree height {subtree_height} exceeds the tree's height, {tree_height}."
+        );
+
+        let num_subtrees = self.num_leafs() >> subtree_height;
+        self.nodes[num_subtrees..2 * num_subtrees].to_vec()
+    }
+
     pub fn indexed_leaves(&self, indices: &[usize]) -> Result<Vec<(usize, Digest)>> {
         let num_leaves = self.num_leafs();
         let invalid_index = MerkleTreeError::LeafIndexInvalid { num_leaves };
@@ -241,6 +341,26 @@ where
     }
 }
 
+impl<H> PartialEq for MerkleTree<H>
+where
+    H: AlgebraicHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.root() == other.root()
+    }
+}
+
+impl<H> Eq for MerkleTree<H> where H: AlgebraicHasher {}
+
+impl<H> Hash for MerkleTree<H>
+where
+    H: AlgebraicHasher,
+{
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.root().hash(state);
+    }
+}
+
 impl<'a, H> Arbitrary<'a> for MerkleTree<H>
 where
     H: AlgebraicHasher,
@@ -273,13 +393,157 @@ where
         if self.is_trivial() {
             return true;
         }
-        let Ok(partial_tree) = PartialMerkleTree::try_from(self) else {
+        self.recover_root() == Some(expected_root)
+    }
+
+    /// The root of the Merkle tree as computed from the indicated leaves and the authentication
+    /// structure, without comparing it to any expected root. Returns `None` if the proof is
+    /// structurally invalid, for example, if it is missing required nodes or contains spurious
+    /// ones. A trivial proof, _i.e._, one without any leaves, gives `None`, too.
+    pub fn recover_root(self) -> Option<Digest> {
+        let partial_tree = PartialMerkleTree::try_from(self).ok()?;
+        partial_tree.root().ok()
+    }
+
+    /// Like [`verify`](Self::verify), but requires the leaf indices to be sorted in strictly
+    /// increasing order, _i.e._, sorted and free of duplicates. Skips sorting the node indices
+    /// during verification, which makes this the faster option if the leaf indices are known to
+    /// be sorted already.
+    ///
+    /// Verification fails if the leaf indices are not sorted or contain duplicates.
+    pub fn verify_presorted(self, expected_root: Digest) -> bool {
+        if !self.leaf_indices().tuple_windows().all(|(l, r)| l < r) {
             return false;
-        };
-        let Ok(computed_root) = partial_tree.root() else {
+        }
+        if self.is_trivial() {
+            return true;
+        }
+        let partial_tree = PartialMerkleTree::try_from_presorted_proof(self);
+        let root = partial_tree.and_then(|tree| tree.root());
+        root == Ok(expected_root)
+    }
+
+    /// Verify that the given root digest is the root of a Merkle tree that contains the indicated
+    /// leaves, where leaf indices, leaf digests, and authentication structure are supplied lazily,
+    /// for example by a decoder. Behaves exactly like [`verify`](Self::verify) on the
+    /// corresponding proof.
+    ///
+    /// The leaf digests and the authentication structure are inserted into the partial Merkle tree
+    /// used for verification directly, without an intermediate proof. Only the leaf indices are
+    /// collected, since all of them are needed to place the authentication structure.
+    ///
+    /// Verification fails if the number of leaf indices and leaf digests differ.
+    pub fn verify_from_iterators(
+        tree_height: usize,
+        leaf_indices: impl ExactSizeIterator<Item = usize>,
+        leaf_digests: impl ExactSizeIterator<Item = Digest>,
+        authentication_structure: impl ExactSizeIterator<Item = Digest>,
+        expected_root: Digest,
+    ) -> bool {
+        let num_leaves = leaf_indices.len();
+        if num_leaves != leaf_digests.len() {
             return false;
+        }
+        if num_leaves == 0 && authentication_structure.len() == 0 {
+            return true;
+        }
+
+        let root = PartialMerkleTree::<H>::unfilled_from_parts(
+            tree_height,
+            leaf_indices.collect(),
+            leaf_digests,
+            authentication_structure,
+        )
+        .and_then(|mut partial_tree| {
+            partial_tree.fill()?;
+            partial_tree.root()
+        });
+        root == Ok(expected_root)
+    }
+
+    /// Verify that the given root digest is the root of a Merkle tree that contains the indicated
+    /// leaves, where every node of the authentication structure is accompanied by its node index.
+    /// The order of the nodes is irrelevant.
+    ///
+    /// If `allow_extra_nodes` is `false`, the supplied nodes must be exactly those of the
+    /// [authentication structure](MerkleTree::authentication_structure). If it is `true`, only
+    /// the needed nodes are used and any additional nodes are ignored, as long as none of them
+    /// conflicts with a node computed during verification. This is useful when interacting with
+    /// parties that de-duplicate authentication structures differently.
+    pub fn verify_with_indexed_nodes(
+        tree_height: usize,
+        indexed_leaves: Vec<(usize, Digest)>,
+        indexed_nodes: &[(usize, Digest)],
+        expected_root: Digest,
+        allow_extra_nodes: bool,
+    ) -> bool {
+        let root = Self::root_from_indexed_nodes(
+            tree_height,
+            indexed_leaves,
+            indexed_nodes,
+            allow_extra_nodes,
+        );
+        root == Ok(expected_root)
+    }
+
+    fn root_from_indexed_nodes(
+        tree_height: usize,
+        indexed_leaves: Vec<(usize, Digest)>,
+        indexed_nodes: &[(usize, Digest)],
+        allow_extra_nodes: bool,
+    ) -> Result<Digest> {
+        if tree_height > MAX_TREE_HEIGHT {
+            return Err(MerkleTreeError::TreeTooHigh);
+        }
+        let num_leaves = 1 << tree_height;
+
+        let mut supplied_nodes = HashMap::new();
+        for &(node_index, digest) in indexed_nodes {
+            let previous_digest = supplied_nodes.insert(node_index, digest);
+            if previous_digest.is_some_and(|previous_digest| previous_digest != digest) {
+                return Err(MerkleTreeError::ConflictingNodeDigest(node_index));
+            }
+        }
+
+        let leaf_indices = indexed_leaves.iter().map(|&(index, _)| index).collect_vec();
+        let needed_node_indices =
+            MerkleTree::<H>::authentication_structure_node_indices(num_leaves, &leaf_indices)?;
+        if !allow_extra_nodes && indexed_nodes.len() != needed_node_indices.len() {
+            return Err(MerkleTreeError::AuthenticationStructureLengthMismatch);
+        }
+
+        let authentication_structure = needed_node_indices
+            .map(|node_index| match supplied_nodes.get(&node_index) {
+                Some(&digest) => Ok(digest),
+                None => Err(MerkleTreeError::MissingNodeIndex(node_index)),
+            })
+            .collect::<Result<_>>()?;
+        let proof = Self {
+            tree_height,
+            indexed_leaves,
+            authentication_structure,
+            _hasher: PhantomData,
         };
-        computed_root == expected_root
+        let partial_tree = PartialMerkleTree::try_from(proof)?;
+
+        for (node_index, digest) in supplied_nodes {
+            let computed_digest = partial_tree.nodes.get(&node_index);
+            if computed_digest.is_some_and(|&computed_digest| computed_digest != digest) {
+                return Err(MerkleTreeError::ConflictingNodeDigest(node_index));
+            }
+        }
+        partial_tree.root()
+    }
+
+    /// Verify an inclusion proof relative to a tree built with
+    /// [`from_digests_salted`](MerkleTreeMaker::from_digests_salted). The proof's
+    /// [`indexed_leaves`](Self::indexed_leaves) are the unsalted leaves. Verification fails if
+    /// the salt differs from the one the tree was built with.
+    pub fn verify_salted(mut self, expected_root: Digest, salt: Digest) -> bool {
+        for (_, leaf) in self.indexed_leaves.iter_mut() {
+            *leaf = H::hash_pair(salt, *leaf);
+        }
+        self.verify(expected_root)
     }
 
     /// Transform the inclusion proof into a list of authentication paths.
@@ -338,15 +602,94 @@ where
         Ok(1 << self.tree_height)
     }
 
+    /// Like the [`TryFrom`] conversion from a [`MerkleTreeInclusionProof`], but requires the
+    /// proof's leaf indices to be sorted and unique.
+    fn try_from_presorted_proof(proof: MerkleTreeInclusionProof<H>) -> Result<Self> {
+        let mut partial_tree = Self::unfilled_from_proof(proof)?;
+        partial_tree.fill_presorted()?;
+        Ok(partial_tree)
+    }
+
+    fn unfilled_from_proof(proof: MerkleTreeInclusionProof<H>) -> Result<Self> {
+        let leaf_indices = proof.leaf_indices().copied().collect();
+        let leaf_digests = proof.indexed_leaves.into_iter().map(|(_, digest)| digest);
+        Self::unfilled_from_parts(
+            proof.tree_height,
+            leaf_indices,
+            leaf_digests,
+            proof.authentication_structure.into_iter(),
+        )
+    }
+
+    /// The leaf digests must be in the same order as the leaf indices.
+    fn unfilled_from_parts(
+        tree_height: usize,
+        leaf_indices: Vec<usize>,
+        leaf_digests: impl Iterator<Item = Digest>,
+        authentication_structure: impl ExactSizeIterator<Item = Digest>,
+    ) -> Result<Self> {
+        let mut partial_tree = PartialMerkleTree {
+            tree_height,
+            leaf_indices,
+            nodes: HashMap::new(),
+            _hasher: PhantomData,
+        };
+
+        let num_leaves = partial_tree.num_leaves()?;
+        if partial_tree.leaf_indices.iter().any(|&i| i >= num_leaves) {
+            return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
+        }
+
+        let node_indices = MerkleTree::<H>::authentication_structure_node_indices(
+            num_leaves,
+            &partial_tree.leaf_indices,
+        )?;
+        if authentication_structure.len() != node_indices.len() {
+            return Err(MerkleTreeError::AuthenticationStructureLengthMismatch);
+        }
+
+        let mut nodes: HashMap<_, _> = node_indices.zip_eq(authentication_structure).collect();
+
+        for (&leaf_index, leaf_digest) in partial_tree.leaf_indices.iter().zip_eq(leaf_digests) {
+            let node_index = leaf_index + num_leaves;
+            if let Vacant(entry) = nodes.entry(node_index) {
+                entry.insert(leaf_digest);
+            } else if nodes[&node_index] != leaf_digest {
+                return Err(MerkleTreeError::RepeatedLeafDigestMismatch);
+            }
+        }
+
+        partial_tree.nodes = nodes;
+        Ok(partial_tree)
+    }
+
     /// Compute all computable digests of the partial Merkle tree, modifying self. Returns an error if self is either
     /// - incomplete, _i.e._, does not contain all the nodes required to compute the root, or
     /// - not minimal, _i.e._, if it contains nodes that can be computed from other nodes.
     pub fn fill(&mut self) -> Result<()> {
-        let mut parent_node_indices = self.first_layer_parent_node_indices()?;
+        let parent_node_indices = self.first_layer_parent_node_indices()?;
+        self.fill_from_first_layer_parent_node_indices(parent_node_indices)
+    }
+
+    /// Like [`fill`](Self::fill), but requires the leaf indices to be sorted and unique, which
+    /// makes sorting the parent node indices unnecessary.
+    fn fill_presorted(&mut self) -> Result<()> {
+        let num_leaves = self.num_leaves()?;
+        let leaf_to_parent_node_index = |&leaf_index| (leaf_index + num_leaves) / 2;
+        let parent_node_indices = self.leaf_indices.iter().map(leaf_to_parent_node_index);
+        let parent_node_indices = parent_node_indices.dedup().collect();
+        self.fill_from_first_layer_parent_node_indices(parent_node_indices)
+    }
 
+    /// The parent node indices must be sorted and unique.
+    fn fill_from_first_layer_parent_node_indices(
+        &mut self,
+        mut parent_node_indices: Vec<usize>,
+    ) -> Result<()> {
         for _ in 0..self.tree_height {
-            for &parent_node_index in &parent_node_indices {
-                self.insert_digest_for_index(parent_node_index)?;
+            let parent_digests = self.digests_for_parent_indices(&parent_node_indices)?;
+            for (parent_node_index, parent_digest) in parent_digests {
+                self.insert_digest_for_index(parent_node_index, parent_digest)?;
             }
             parent_node_indices = Self::move_indices_one_layer_up(parent_node_indices);
         }
@@ -354,6 +697,22 @@ where
         Ok(())
     }
 
+    /// Compute the digests of the indicated parent nodes from their children. All children live
+    /// on the same layer, which is not modified, allowing the hashing to happen in parallel if
+    /// the layer is wide enough.
+    fn digests_for_parent_indices(&self, parent_indices: &[usize]) -> Result<Vec<(usize, Digest)>> {
+        let digest_for_index = |&parent_index: &usize| {
+            let (left_child, right_child) = self.children_of_node(parent_index)?;
+            let parent_digest = H::hash_pair(left_child, right_child);
+            Ok((parent_index, parent_digest))
+        };
+
+        if parent_indices.len() < *PARALLELIZATION_CUTOFF {
+            return parent_indices.iter().map(digest_for_index).collect();
+        }
+        parent_indices.par_iter().map(digest_for_index).collect()
+    }
+
     /// Any parent node index is included only once. This guarantees that the number of hash operations is minimal.
     fn first_layer_parent_node_indices(&self) -> Result<Vec<usize>> {
         let num_leaves = self.num_leaves()?;
@@ -366,10 +725,11 @@ where
         Ok(parent_node_indices)
     }
 
-    fn insert_digest_for_index(&mut self, parent_index: usize) -> Result<()> {
-        let (left_child, right_child) = self.children_of_node(parent_index)?;
-        let parent_digest = H::hash_pair(left_child, right_child);
-
+    fn insert_digest_for_index(
+        &mut self,
+        parent_index: usize,
+        parent_digest: Digest,
+    ) -> Result<()> {
         match self.nodes.insert(parent_index, parent_digest) {
             Some(_) => Err(MerkleTreeError::SpuriousNodeIndex(parent_index)),
             None => Ok(()),
@@ -405,15 +765,9 @@ where
     /// Fails if the partial Merkle tree does not contain the entire authentication path.
     fn authentication_path_for_index(&self, leaf_index: usize) -> Result<Vec<Digest>> {
         let num_leaves = self.num_leaves()?;
-        let mut authentication_path = vec![];
-        let mut node_index = leaf_index + num_leaves;
-        while node_index > ROOT_INDEX {
-            let sibling_index = node_index ^ 1;
-            let sibling = self.node(sibling_index)?;
-            authentication_path.push(sibling);
-            node_index /= 2;
-        }
-        Ok(authentication_path)
+        authentication_path_node_indices(num_leaves, leaf_index)
+            .map(|sibling_index| self.node(sibling_index))
+            .collect()
     }
 }
 
@@ -424,41 +778,7 @@ where
     type Error = MerkleTreeError;
 
     fn try_from(proof: MerkleTreeInclusionProof<H>) -> Result<Self> {
-        let leaf_indices = proof.leaf_indices().copied().collect();
-        let mut partial_tree = PartialMerkleTree {
-            tree_height: proof.tree_height,
-            leaf_indices,
-            nodes: HashMap::new(),
-            _hasher: PhantomData,
-        };
-
-        let num_leaves = partial_tree.num_leaves()?;
-        if partial_tree.leaf_indices.iter().any(|&i| i >= num_leaves) {
-            return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
-        }
-
-        let node_indices = MerkleTree::<H>::authentication_structure_node_indices(
-            num_leaves,
-            &partial_tree.leaf_indices,
-        )?;
-        if proof.authentication_structure.len() != node_indices.len() {
-            return Err(MerkleTreeError::AuthenticationStructureLengthMismatch);
-        }
-
-        let mut nodes: HashMap<_, _> = node_indices
-            .zip_eq(proof.authentication_structure)
-            .collect();
-
-        for (leaf_index, leaf_digest) in proof.indexed_leaves {
-            let node_index = leaf_index + num_leaves;
-            if let Vacant(entry) = nodes.entry(node_index) {
-                entry.insert(leaf_digest);
-            } else if nodes[&node_index] != leaf_digest {
-                return Err(MerkleTreeError::RepeatedLeafDigestMismatch);
-            }
-        }
-
-        partial_tree.nodes = nodes;
+        let mut partial_tree = Self::unfilled_from_proof(proof)?;
         partial_tree.fill()?;
         Ok(partial_tree)
     }
@@ -471,19 +791,36 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
     /// Takes an array of digests and builds a MerkleTree over them. The digests are copied as the
     /// leaves of the tree.
     ///
+    /// Levels of the tree with at least as many nodes as the parallelization cutoff are computed
+    /// in parallel. The cutoff defaults to 256 and can be overridden through the environment
+    /// variable `MERKLE_TREE_PARALLELIZATION_CUTOFF`. To set it explicitly, use
+    /// [`from_digests_with_threshold`](CpuParallel::from_digests_with_threshold).
+    ///
     /// # Errors
     ///
     /// - If the number of digests is 0.
     /// - If the number of digests is not a power of two.
     fn from_digests(digests: &[Digest]) -> Result<MerkleTree<H>> {
-        if digests.is_empty() {
-            return Err(MerkleTreeError::TooFewLeaves);
-        }
+        Self::from_digests_with_threshold(digests, *PARALLELIZATION_CUTOFF)
+    }
+}
 
+impl CpuParallel {
+    /// Like [`from_digests`](MerkleTreeMaker::from_digests), but with an explicit
+    /// parallelization threshold: levels of the tree with at least `threshold` nodes are
+    /// computed in parallel, all other levels sequentially. The resulting tree does not depend
+    /// on the threshold.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digests is 0.
+    /// - If the number of digests is not a power of two.
+    pub fn from_digests_with_threshold<H: AlgebraicHasher>(
+        digests: &[Digest],
+        threshold: usize,
+    ) -> Result<MerkleTree<H>> {
+        Self::ensure_valid_number_of_leaves(digests.len())?;
         let leaves_count = digests.len();
-        if !leaves_count.is_power_of_two() {
-            return Err(MerkleTreeError::IncorrectNumberOfLeaves);
-        }
 
         // nodes[0] is never used for anything.
         let filler = Digest::default();
@@ -491,10 +828,69 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
         nodes[leaves_count..(leaves_count + leaves_count)]
             .clone_from_slice(&digests[..leaves_count]);
 
+        Self::fill_internal_nodes(&mut nodes, threshold, H::hash_pair);
+        let tree = MerkleTree {
+            nodes,
+            _hasher: PhantomData,
+        };
+        Ok(tree)
+    }
+
+    /// Like [`from_digests`](MerkleTreeMaker::from_digests), but consumes the digests from an
+    /// iterator, writing them directly into the tree's nodes. This avoids materializing the
+    /// leaves in a separate slice.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digests is 0.
+    /// - If the number of digests is not a power of two.
+    /// - If the iterator yields a different number of digests than its reported length.
+    pub fn from_digest_iter<H: AlgebraicHasher>(
+        mut digests: impl ExactSizeIterator<Item = Digest>,
+    ) -> Result<MerkleTree<H>> {
+        let leaves_count = digests.len();
+        Self::ensure_valid_number_of_leaves(leaves_count)?;
+
+        // nodes[0] is never used for anything.
+        let mut nodes = Vec::with_capacity(2 * leaves_count);
+        nodes.resize(leaves_count, Digest::default());
+        nodes.extend(digests.by_ref().take(leaves_count));
+        if nodes.len() != 2 * leaves_count || digests.next().is_some() {
+            return Err(MerkleTreeError::IncorrectNumberOfLeaves);
+        }
+
+        Self::fill_internal_nodes(&mut nodes, *PARALLELIZATION_CUTOFF, H::hash_pair);
+        let tree = MerkleTree {
+            nodes,
+            _hasher: PhantomData,
+        };
+        Ok(tree)
+    }
+
+    fn ensure_valid_number_of_leaves(leaves_count: usize) -> Result<()> {
+        if leaves_count == 0 {
+            return Err(MerkleTreeError::TooFewLeaves);
+        }
+        if !leaves_count.is_power_of_two() {
+            return Err(MerkleTreeError::IncorrectNumberOfLeaves);
+        }
+        Ok(())
+    }
+
+    /// Compute all internal nodes from the leaves, which make up the upper half of `nodes`.
+    fn fill_internal_nodes(
+        nodes: &mut [Digest],
+        threshold: usize,
+        hash_pair: impl Fn(Digest, Digest) -> Digest + Sync,
+    ) {
+        let leaves_count = nodes.len() / 2;
+
         // Parallel digest calculations
         let mut node_count_on_this_level: usize = leaves_count / 2;
         let mut count_acc: usize = 0;
-        while node_count_on_this_level >= *PARALLELIZATION_CUTOFF {
+        // A threshold of 0 would never terminate once the root level is passed.
+        let threshold = threshold.max(1);
+        while node_count_on_this_level >= threshold {
             let mut local_digests: Vec<Digest> = Vec::with_capacity(node_count_on_this_level);
             (0..node_count_on_this_level)
                 .into_par_iter()
@@ -502,7 +898,7 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
                     let j = node_count_on_this_level + i;
                     let left_child = nodes[j * 2];
                     let right_child = nodes[j * 2 + 1];
-                    H::hash_pair(left_child, right_child)
+                    hash_pair(left_child, right_child)
                 })
                 .collect_into_vec(&mut local_digests);
             nodes[node_count_on_this_level..(node_count_on_this_level + node_count_on_this_level)]
@@ -512,8 +908,88 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
         }
 
         // Sequential digest calculations
-        for i in (1..(digests.len() - count_acc)).rev() {
-            nodes[i] = H::hash_pair(nodes[i * 2], nodes[i * 2 + 1]);
+        for i in (1..(leaves_count - count_acc)).rev() {
+            nodes[i] = hash_pair(nodes[i * 2], nodes[i * 2 + 1]);
+        }
+    }
+
+    /// Computes the root of the Merkle tree over the given digests without materializing the
+    /// tree. Only a stack of at most `O(log n)` partial subtree roots is kept in memory.
+    ///
+    /// If the number of digests is a power of two, the result is identical to the root of the
+    /// tree built by [`from_digests`](MerkleTreeMaker::from_digests). Otherwise, the roots of
+    /// the maximal perfect subtrees are [bagged](bag_peaks), like the peaks of a Merkle Mountain
+    /// Range. For zero digests, this is the bagging of no peaks.
+    pub fn root_from_digests_streaming<H: AlgebraicHasher>(
+        digests: impl Iterator<Item = Digest>,
+    ) -> Digest {
+        // Invariant: the heights on the stack are strictly decreasing.
+        let mut stack: Vec<(u32, Digest)> = vec![];
+        for digest in digests {
+            let mut height = 0;
+            let mut root = digest;
+            while let Some(&(top_height, top_root)) = stack.last() {
+                if top_height != height {
+                    break;
+                }
+                stack.pop();
+                root = H::hash_pair(top_root, root);
+                height += 1;
+            }
+            stack.push((height, root));
+        }
+
+        let peaks = stack.into_iter().map(|(_, root)| root).collect_vec();
+        bag_peaks::<H>(&peaks)
+    }
+}
+
+/// Builds [Merkle trees](MerkleTree) level by level, hashing all nodes of one level using
+/// [`hash_pair_batch`](AlgebraicHasher::hash_pair_batch). Each level is split into batches that
+/// are processed in parallel.
+///
+/// The resulting trees are identical to those built by [`CpuParallel`].
+#[derive(Debug)]
+pub struct BatchedCpu;
+
+impl BatchedCpu {
+    const BATCH_SIZE: usize = 64;
+}
+
+impl<H: AlgebraicHasher> MerkleTreeMaker<H> for BatchedCpu {
+    /// Takes an array of digests and builds a MerkleTree over them. The digests are copied as the
+    /// leaves of the tree.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digests is 0.
+    /// - If the number of digests is not a power of two.
+    fn from_digests(digests: &[Digest]) -> Result<MerkleTree<H>> {
+        CpuParallel::ensure_valid_number_of_leaves(digests.len())?;
+        let num_leaves = digests.len();
+
+        // nodes[0] is never used for anything.
+        let mut nodes = vec![Digest::default(); 2 * num_leaves];
+        nodes[num_leaves..].copy_from_slice(digests);
+
+        let mut num_nodes_on_this_level = num_leaves / 2;
+        while num_nodes_on_this_level > 0 {
+            let (parents_and_above, children_and_below) =
+                nodes.split_at_mut(2 * num_nodes_on_this_level);
+            let parents = &mut parents_and_above[num_nodes_on_this_level..];
+            let children = &children_and_below[..2 * num_nodes_on_this_level];
+            let child_pairs = children
+                .chunks_exact(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect_vec();
+
+            parents
+                .par_chunks_mut(Self::BATCH_SIZE)
+                .zip(child_pairs.par_chunks(Self::BATCH_SIZE))
+                .for_each(|(parents, child_pairs)| {
+                    parents.copy_from_slice(&H::hash_pair_batch(child_pairs))
+                });
+            num_nodes_on_this_level /= 2;
         }
 
         let tree = MerkleTree {
@@ -524,23 +1000,167 @@ impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
-pub enum MerkleTreeError {
-    #[error("All leaf indices must be valid, i.e., less than {num_leaves}.")]
-    LeafIndexInvalid { num_leaves: usize },
+/// A Merkle tree maker that reuses the memory of previously built trees. Building many trees of
+/// the same size this way avoids repeatedly allocating and freeing their nodes.
+///
+/// Every built tree owns its nodes. Once a tree is no longer needed, hand it back using
+/// [`recycle`](Self::recycle); the next tree is then built in its memory. If no tree was recycled,
+/// building a tree allocates, just like [`CpuParallel`] does.
+///
+/// The resulting trees are identical to those built by [`CpuParallel`].
+#[derive(Debug, Default)]
+pub struct ReusableCpu {
+    scratch: Vec<Digest>,
+}
 
-    #[error("The length of the supplied authentication structure must match the expected length.")]
-    AuthenticationStructureLengthMismatch,
+impl ReusableCpu {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    #[error("Leaf digests of repeated indices must be identical.")]
-    RepeatedLeafDigestMismatch,
+    /// Build a Merkle tree over the given leaves, reusing the memory of the most recently
+    /// [recycled](Self::recycle) tree, if any.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digests is 0.
+    /// - If the number of digests is not a power of two.
+    pub fn from_digests_into<H: AlgebraicHasher>(
+        &mut self,
+        digests: &[Digest],
+    ) -> Result<MerkleTree<H>> {
+        CpuParallel::ensure_valid_number_of_leaves(digests.len())?;
+        let leaves_count = digests.len();
 
-    #[error("The partial tree must be minimal. Node {0} was supplied but can be computed.")]
-    SpuriousNodeIndex(usize),
+        // nodes[0] is never used for anything.
+        let mut nodes = std::mem::take(&mut self.scratch);
+        nodes.clear();
+        nodes.resize(leaves_count, Digest::default());
+        nodes.extend_from_slice(digests);
+
+        CpuParallel::fill_internal_nodes(&mut nodes, *PARALLELIZATION_CUTOFF, H::hash_pair);
+        let tree = MerkleTree {
+            nodes,
+            _hasher: PhantomData,
+        };
+        Ok(tree)
+    }
+
+    /// Make the memory of the given tree available for building the next tree.
+    pub fn recycle<H: AlgebraicHasher>(&mut self, tree: MerkleTree<H>) {
+        self.scratch = tree.nodes;
+    }
+}
+
+/// A Merkle tree whose hash function is selected at runtime. Apart from that, it behaves like
+/// [`MerkleTree`], trading some speed for flexibility.
+#[derive(Debug, Clone)]
+pub struct DynMerkleTree {
+    nodes: Vec<Digest>,
+    hasher: Arc<dyn DynHasher>,
+}
+
+impl DynMerkleTree {
+    /// Build a Merkle tree over the given leaves, using the given hash function.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digests is 0.
+    /// - If the number of digests is not a power of two.
+    pub fn new(digests: &[Digest], hasher: Arc<dyn DynHasher>) -> Result<Self> {
+        CpuParallel::ensure_valid_number_of_leaves(digests.len())?;
+        let leaves_count = digests.len();
+
+        // nodes[0] is never used for anything.
+        let mut nodes = vec![Digest::default(); 2 * leaves_count];
+        nodes[leaves_count..].copy_from_slice(digests);
+
+        let hash_pair = |left: Digest, right: Digest| hasher.hash_pair(&left, &right);
+        CpuParallel::fill_internal_nodes(&mut nodes, *PARALLELIZATION_CUTOFF, hash_pair);
+        Ok(Self { nodes, hasher })
+    }
+
+    pub fn hasher(&self) -> &Arc<dyn DynHasher> {
+        &self.hasher
+    }
+
+    pub fn root(&self) -> Digest {
+        self.nodes[ROOT_INDEX]
+    }
+
+    pub fn num_leafs(&self) -> usize {
+        self.nodes.len() / 2
+    }
+
+    pub fn height(&self) -> usize {
+        self.num_leafs().ilog2() as usize
+    }
+
+    pub fn leaves(&self) -> &[Digest] {
+        &self.nodes[self.num_leafs()..]
+    }
+
+    /// The authentication path for the indicated leaf, ordered from the leaf's sibling to the
+    /// root's child. See also [`MerkleTree::all_authentication_paths`].
+    pub fn authentication_path(&self, leaf_index: usize) -> Result<Vec<Digest>> {
+        let num_leaves = self.num_leafs();
+        if leaf_index >= num_leaves {
+            return Err(MerkleTreeError::LeafIndexInvalid { num_leaves });
+        }
+
+        let authentication_path = authentication_path_node_indices(num_leaves, leaf_index)
+            .map(|node_index| self.nodes[node_index])
+            .collect();
+        Ok(authentication_path)
+    }
+
+    /// Verify that the indicated leaf is in the Merkle tree with the given root, using the
+    /// given hash function. The height of the tree is the length of the authentication path.
+    pub fn verify_authentication_path(
+        hasher: &dyn DynHasher,
+        expected_root: Digest,
+        leaf_index: usize,
+        leaf: Digest,
+        authentication_path: &[Digest],
+    ) -> bool {
+        let tree_height = authentication_path.len();
+        if tree_height > MAX_TREE_HEIGHT || leaf_index >= 1 << tree_height {
+            return false;
+        }
+
+        let mut node_index = leaf_index + (1 << tree_height);
+        let mut node = leaf;
+        for sibling in authentication_path {
+            node = match node_index % 2 {
+                0 => hasher.hash_pair(&node, sibling),
+                _ => hasher.hash_pair(sibling, &node),
+            };
+            node_index /= 2;
+        }
+        node == expected_root
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum MerkleTreeError {
+    #[error("All leaf indices must be valid, i.e., less than {num_leaves}.")]
+    LeafIndexInvalid { num_leaves: usize },
+
+    #[error("The length of the supplied authentication structure must match the expected length.")]
+    AuthenticationStructureLengthMismatch,
+
+    #[error("Leaf digests of repeated indices must be identical.")]
+    RepeatedLeafDigestMismatch,
+
+    #[error("The partial tree must be minimal. Node {0} was supplied but can be computed.")]
+    SpuriousNodeIndex(usize),
 
     #[error("The partial tree must contain all necessary information. Node {0} is missing.")]
     MissingNodeIndex(usize),
 
+    #[error("The digest supplied for node {0} conflicts with another digest for the same node.")]
+    ConflictingNodeDigest(usize),
+
     #[error("Could not compute the root. Maybe no leaf indices were supplied?")]
     RootNotFound,
 
@@ -565,6 +1185,7 @@ pub mod merkle_tree_test {
     use crate::shared_math::b_field_element::BFieldElement;
     use crate::shared_math::digest::digest_tests::DigestCorruptor;
     use crate::shared_math::tip5::Tip5;
+    use crate::util_types::algebraic_hasher::StaticHasher;
 
     use super::*;
 
@@ -612,6 +1233,49 @@ pub mod merkle_tree_test {
         }
     }
 
+    fn hash_of(tree: &MerkleTree<Tip5>) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        tree.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[proptest(cases = 20)]
+    fn merkle_trees_from_identical_leaves_are_equal_and_hash_identically(
+        #[strategy(0_usize..8)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        let same_tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        prop_assert_eq!(&tree, &same_tree);
+        prop_assert_eq!(hash_of(&tree), hash_of(&same_tree));
+    }
+
+    #[proptest(cases = 20)]
+    fn merkle_trees_from_different_leaves_are_different(
+        #[strategy(0_usize..8)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+        #[strategy(0_usize..#leaves.len())] leaf_index: usize,
+        corruptor: DigestCorruptor,
+    ) {
+        let mut other_leaves = leaves.clone();
+        other_leaves[leaf_index] = corruptor.corrupt_digest(leaves[leaf_index])?;
+
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        let other_tree: MerkleTree<Tip5> = CpuParallel::from_digests(&other_leaves).unwrap();
+        prop_assert_ne!(&tree, &other_tree);
+        prop_assert_ne!(hash_of(&tree), hash_of(&other_tree));
+    }
+
+    #[test]
+    fn merkle_trees_can_be_used_as_map_keys() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(3);
+        let other_tree = MerkleTree::<Tip5>::test_tree_of_height(4);
+        let trees = HashSet::from([tree.clone(), other_tree.clone(), tree.clone()]);
+        assert_eq!(2, trees.len());
+        assert!(trees.contains(&tree));
+        assert!(trees.contains(&other_tree));
+    }
+
     #[test]
     fn building_merkle_tree_from_empty_list_of_digests_fails_with_expected_error() {
         let maybe_tree: Result<MerkleTree<Tip5>> = CpuParallel::from_digests(&[]);
@@ -633,6 +1297,68 @@ pub mod merkle_tree_test {
         assert_eq!(digest, tree.root());
     }
 
+    #[proptest(cases = 30)]
+    fn nodes_are_accessible_by_valid_node_indices_only(
+        #[strategy(0_usize..10)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        prop_assert_eq!(2 * tree.num_leafs() - 1, tree.num_nodes());
+
+        prop_assert_eq!(None, tree.node(0));
+        prop_assert_eq!(Some(tree.root()), tree.node(ROOT_INDEX));
+        prop_assert_eq!(Some(leaves[leaves.len() - 1]), tree.node(tree.num_nodes()));
+        prop_assert_eq!(None, tree.node(tree.num_nodes() + 1));
+        prop_assert_eq!(None, tree.node(usize::MAX));
+    }
+
+    #[proptest(cases = 30)]
+    fn leaves_in_range_agree_with_individual_leaves(
+        #[strategy(0_usize..10)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+        #[strategy(0_usize..=#leaves.len())] end: usize,
+        #[strategy(0_usize..=#end)] start: usize,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        let individual_leaves = (start..end).map(|i| tree.leaf(i).unwrap()).collect_vec();
+        prop_assert_eq!(individual_leaves, tree.leaves_in_range(start..end));
+    }
+
+    #[proptest(cases = 30)]
+    fn subtree_roots_agree_with_roots_of_trees_built_from_leaf_blocks(
+        #[strategy(arb())] tree: MerkleTree<Tip5>,
+        #[strategy(0..=#tree.height())] subtree_height: usize,
+    ) {
+        let subtree_roots = tree.subtree_roots(subtree_height);
+        prop_assert_eq!(tree.num_leafs() >> subtree_height, subtree_roots.len());
+
+        let leaf_blocks = tree.leaves().chunks(1 << subtree_height);
+        for (leaf_block, subtree_root) in leaf_blocks.zip_eq(subtree_roots) {
+            let subtree: MerkleTree<Tip5> = CpuParallel::from_digests(leaf_block).unwrap();
+            prop_assert_eq!(subtree.root(), subtree_root);
+        }
+    }
+
+    #[proptest(cases = 10)]
+    fn extreme_subtree_heights_give_leaves_or_root(#[strategy(arb())] tree: MerkleTree<Tip5>) {
+        prop_assert_eq!(tree.leaves().to_vec(), tree.subtree_roots(0));
+        prop_assert_eq!(vec![tree.root()], tree.subtree_roots(tree.height()));
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the tree's height")]
+    fn subtree_roots_of_too_high_subtrees_panics() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(3);
+        tree.subtree_roots(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the number of leaves")]
+    fn leaves_in_range_extending_beyond_last_leaf_panics() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(3);
+        tree.leaves_in_range(4..9);
+    }
+
     #[proptest]
     fn building_merkle_tree_from_list_of_digests_with_incorrect_number_of_leaves_fails_with_expected_error(
         #[filter(!#num_leaves.is_power_of_two())]
@@ -646,6 +1372,262 @@ pub mod merkle_tree_test {
         assert_eq!(MerkleTreeError::IncorrectNumberOfLeaves, err);
     }
 
+    #[proptest(cases = 30)]
+    fn parallelization_threshold_does_not_influence_tree(
+        #[strategy(0_usize..10)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        for threshold in [0, 1, 2, 16, 256, 1 << 20] {
+            let other_tree = CpuParallel::from_digests_with_threshold(&leaves, threshold).unwrap();
+            prop_assert_eq!(&tree, &other_tree);
+        }
+    }
+
+    #[test]
+    fn building_merkle_tree_from_iterator_agrees_with_building_from_slice() {
+        let num_leaves = 1 << 14;
+        let leaves = (0..num_leaves)
+            .map(|i| Tip5::hash_varlen(&[BFieldElement::new(i)]))
+            .collect_vec();
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        let tree_from_iter = CpuParallel::from_digest_iter(leaves.into_iter()).unwrap();
+        assert_eq!(tree, tree_from_iter);
+    }
+
+    #[test]
+    fn building_merkle_tree_from_iterator_with_bad_number_of_digests_fails() {
+        let maybe_tree = CpuParallel::from_digest_iter::<Tip5>(std::iter::empty());
+        assert_eq!(MerkleTreeError::TooFewLeaves, maybe_tree.unwrap_err());
+
+        let digests = vec![Digest::default(); 3];
+        let maybe_tree = CpuParallel::from_digest_iter::<Tip5>(digests.into_iter());
+        assert_eq!(
+            MerkleTreeError::IncorrectNumberOfLeaves,
+            maybe_tree.unwrap_err()
+        );
+    }
+
+    fn hasher_from_config(name: &str) -> Arc<dyn DynHasher> {
+        match name {
+            "tip5" => Arc::new(StaticHasher::<Tip5>::new()),
+            "blake3" => Arc::new(StaticHasher::<blake3::Hasher>::new()),
+            _ => panic!("unknown hash function {name}"),
+        }
+    }
+
+    #[proptest(cases = 30)]
+    fn dyn_merkle_tree_agrees_with_merkle_tree(
+        #[strategy(0_usize..8)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+    ) {
+        let tip5_tree = DynMerkleTree::new(&leaves, hasher_from_config("tip5")).unwrap();
+        let static_tip5_tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        prop_assert_eq!(static_tip5_tree.root(), tip5_tree.root());
+
+        let blake3_tree = DynMerkleTree::new(&leaves, hasher_from_config("blake3")).unwrap();
+        let static_blake3_tree: MerkleTree<blake3::Hasher> =
+            CpuParallel::from_digests(&leaves).unwrap();
+        prop_assert_eq!(static_blake3_tree.root(), blake3_tree.root());
+
+        prop_assert_ne!(tip5_tree.root(), blake3_tree.root());
+        prop_assert_eq!(tree_height, tip5_tree.height());
+        prop_assert_eq!(leaves.as_slice(), tip5_tree.leaves());
+    }
+
+    #[proptest(cases = 30)]
+    fn authentication_paths_of_dyn_merkle_tree_can_be_verified(
+        #[strategy(0_usize..8)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+        #[strategy(0_usize..1 << #tree_height)] leaf_index: usize,
+        corruptor: DigestCorruptor,
+    ) {
+        for hash_function in ["tip5", "blake3"] {
+            let tree = DynMerkleTree::new(&leaves, hasher_from_config(hash_function)).unwrap();
+            let hasher = tree.hasher().as_ref();
+            let path = tree.authentication_path(leaf_index).unwrap();
+            let leaf = leaves[leaf_index];
+            let verdict = DynMerkleTree::verify_authentication_path(
+                hasher,
+                tree.root(),
+                leaf_index,
+                leaf,
+                &path,
+            );
+            prop_assert!(verdict);
+
+            let bad_leaf = corruptor.corrupt_digest(leaf)?;
+            let verdict = DynMerkleTree::verify_authentication_path(
+                hasher,
+                tree.root(),
+                leaf_index,
+                bad_leaf,
+                &path,
+            );
+            prop_assert!(!verdict);
+        }
+    }
+
+    #[test]
+    fn dyn_merkle_tree_rejects_bad_number_of_leaves() {
+        let hasher = hasher_from_config("tip5");
+        let err = DynMerkleTree::new(&[], hasher.clone()).unwrap_err();
+        assert_eq!(MerkleTreeError::TooFewLeaves, err);
+
+        let err = DynMerkleTree::new(&[Digest::default(); 3], hasher).unwrap_err();
+        assert_eq!(MerkleTreeError::IncorrectNumberOfLeaves, err);
+    }
+
+    #[proptest(cases = 20)]
+    fn reusable_merkle_tree_maker_agrees_with_parallel_merkle_tree_maker(
+        #[strategy(0_usize..10)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+        #[strategy(0_usize..10)] other_tree_height: usize,
+        #[strategy(vec(arb(), 1 << #other_tree_height))] other_leaves: Vec<Digest>,
+    ) {
+        let mut maker = ReusableCpu::new();
+        let tree: MerkleTree<Tip5> = maker.from_digests_into(&leaves).unwrap();
+        prop_assert_eq!(CpuParallel::from_digests(&leaves).unwrap(), tree.clone());
+
+        maker.recycle(tree);
+        let other_tree: MerkleTree<Tip5> = maker.from_digests_into(&other_leaves).unwrap();
+        prop_assert_eq!(
+            CpuParallel::from_digests(&other_leaves).unwrap(),
+            other_tree
+        );
+    }
+
+    #[test]
+    fn reusable_merkle_tree_maker_reuses_memory_of_recycled_tree() {
+        let leaves = vec![Digest::default(); 1 << 10];
+        let mut maker = ReusableCpu::new();
+        let tree: MerkleTree<Tip5> = maker.from_digests_into(&leaves).unwrap();
+        let nodes_ptr = tree.nodes().as_ptr();
+
+        maker.recycle(tree);
+        let tree: MerkleTree<Tip5> = maker.from_digests_into(&leaves).unwrap();
+        assert_eq!(nodes_ptr, tree.nodes().as_ptr());
+    }
+
+    #[test]
+    fn reusable_merkle_tree_maker_rejects_bad_number_of_leaves() {
+        let mut maker = ReusableCpu::new();
+        let err = maker.from_digests_into::<Tip5>(&[]).unwrap_err();
+        assert_eq!(MerkleTreeError::TooFewLeaves, err);
+
+        let err = maker
+            .from_digests_into::<Tip5>(&[Digest::default(); 3])
+            .unwrap_err();
+        assert_eq!(MerkleTreeError::IncorrectNumberOfLeaves, err);
+    }
+
+    #[test]
+    fn batched_merkle_tree_maker_agrees_with_parallel_merkle_tree_maker() {
+        let num_leaves = 1 << 16;
+        let leaves = (0..num_leaves)
+            .map(|i| Tip5::hash_varlen(&[BFieldElement::new(i)]))
+            .collect_vec();
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        let batched_tree: MerkleTree<Tip5> = BatchedCpu::from_digests(&leaves).unwrap();
+        assert_eq!(tree.nodes(), batched_tree.nodes());
+    }
+
+    #[proptest(cases = 30)]
+    fn batched_merkle_tree_maker_agrees_with_parallel_merkle_tree_maker_on_small_trees(
+        #[strategy(0_usize..10)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests(&leaves).unwrap();
+        let batched_tree: MerkleTree<Tip5> = BatchedCpu::from_digests(&leaves).unwrap();
+        prop_assert_eq!(tree, batched_tree);
+    }
+
+    #[test]
+    fn building_batched_merkle_tree_from_bad_number_of_digests_fails() {
+        let maybe_tree: Result<MerkleTree<Tip5>> = BatchedCpu::from_digests(&[]);
+        assert_eq!(MerkleTreeError::TooFewLeaves, maybe_tree.unwrap_err());
+
+        let maybe_tree: Result<MerkleTree<Tip5>> =
+            BatchedCpu::from_digests(&[Digest::default(); 3]);
+        assert_eq!(
+            MerkleTreeError::IncorrectNumberOfLeaves,
+            maybe_tree.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn streaming_root_computation_agrees_with_root_of_built_tree() {
+        for tree_height in 0..10 {
+            let tree = MerkleTree::<Tip5>::test_tree_of_height(tree_height);
+            let leaves = tree.leaves().iter().copied();
+            let root = CpuParallel::root_from_digests_streaming::<Tip5>(leaves);
+            assert_eq!(tree.root(), root);
+        }
+    }
+
+    #[proptest(cases = 30)]
+    fn streaming_root_computation_agrees_with_root_of_arbitrary_tree(
+        #[strategy(arb())] tree: MerkleTree<Tip5>,
+    ) {
+        let leaves = tree.leaves().iter().copied();
+        let root = CpuParallel::root_from_digests_streaming::<Tip5>(leaves);
+        prop_assert_eq!(tree.root(), root);
+    }
+
+    #[proptest(cases = 30)]
+    fn streaming_root_computation_bags_peaks_for_non_power_of_two_number_of_leaves(
+        #[strategy(vec(arb(), 0..100))] digests: Vec<Digest>,
+    ) {
+        let peaks = crate::shared_math::other::indices_of_set_bits(digests.len() as u64)
+            .into_iter()
+            .scan(0, |num_processed_digests, tree_height| {
+                let start = *num_processed_digests;
+                *num_processed_digests += 1 << tree_height;
+                Some(&digests[start..*num_processed_digests])
+            })
+            .map(|leaves| CpuParallel::from_digests(leaves).unwrap())
+            .map(|tree: MerkleTree<Tip5>| tree.root())
+            .collect_vec();
+        let expected_root = bag_peaks::<Tip5>(&peaks);
+
+        let root = CpuParallel::root_from_digests_streaming::<Tip5>(digests.into_iter());
+        prop_assert_eq!(expected_root, root);
+    }
+
+    #[proptest(cases = 30)]
+    fn identical_leaves_under_different_salts_give_different_roots(
+        #[strategy(0_usize..8)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+        salt: Digest,
+        #[filter(#salt != #other_salt)] other_salt: Digest,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests_salted(&leaves, salt).unwrap();
+        let other_tree: MerkleTree<Tip5> =
+            CpuParallel::from_digests_salted(&leaves, other_salt).unwrap();
+        prop_assert_ne!(tree.root(), other_tree.root());
+    }
+
+    #[proptest(cases = 30)]
+    fn salted_inclusion_proof_verifies_only_with_the_same_salt(
+        #[strategy(0_usize..8)] tree_height: usize,
+        #[strategy(vec(arb(), 1 << #tree_height))] leaves: Vec<Digest>,
+        #[strategy(vec(0..#leaves.len(), 1..=#leaves.len()))] leaf_indices: Vec<usize>,
+        salt: Digest,
+        #[filter(#salt != #other_salt)] other_salt: Digest,
+    ) {
+        let tree: MerkleTree<Tip5> = CpuParallel::from_digests_salted(&leaves, salt).unwrap();
+        let proof = MerkleTreeInclusionProof {
+            tree_height,
+            indexed_leaves: leaf_indices.iter().map(|&i| (i, leaves[i])).collect(),
+            authentication_structure: tree.authentication_structure(&leaf_indices).unwrap(),
+            _hasher: PhantomData,
+        };
+
+        prop_assert!(proof.clone().verify_salted(tree.root(), salt));
+        prop_assert!(!proof.clone().verify_salted(tree.root(), other_salt));
+        prop_assert!(!proof.verify(tree.root()));
+    }
+
     #[proptest(cases = 100)]
     fn accessing_number_of_leaves_and_height_never_panics(
         #[strategy(arb())] merkle_tree: MerkleTree<Tip5>,
@@ -669,6 +1651,225 @@ pub mod merkle_tree_test {
         prop_assert!(verdict);
     }
 
+    #[proptest(cases = 30)]
+    fn root_recovered_from_honest_proof_is_root_of_tree(
+        #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
+    ) {
+        let recovered_root = test_tree.proof().recover_root();
+        prop_assert_eq!(Some(test_tree.tree.root()), recovered_root);
+    }
+
+    #[test]
+    fn root_cannot_be_recovered_from_trivial_proof() {
+        let proof = MerkleTreeInclusionProof::<Tip5>::default();
+        assert_eq!(None, proof.recover_root());
+    }
+
+    #[proptest(cases = 30)]
+    fn root_cannot_be_recovered_from_proof_with_missing_authentication_node(
+        #[filter(!#test_tree.proof().authentication_structure.is_empty())]
+        test_tree: MerkleTreeToTest,
+    ) {
+        let mut proof = test_tree.proof();
+        proof.authentication_structure.pop();
+        prop_assert_eq!(None, proof.recover_root());
+    }
+
+    #[proptest(cases = 30)]
+    fn verifying_from_iterators_agrees_with_verifying_proof(
+        test_tree: MerkleTreeToTest,
+        corruptor: DigestCorruptor,
+    ) {
+        let proof = test_tree.proof();
+        let verify_from_iterators = |root| {
+            MerkleTreeInclusionProof::<Tip5>::verify_from_iterators(
+                proof.tree_height,
+                proof.indexed_leaves.iter().map(|&(index, _)| index),
+                proof.indexed_leaves.iter().map(|&(_, leaf)| leaf),
+                proof.authentication_structure.iter().copied(),
+                root,
+            )
+        };
+
+        let root = test_tree.tree.root();
+        prop_assert_eq!(proof.clone().verify(root), verify_from_iterators(root));
+
+        let bad_root = corruptor.corrupt_digest(root)?;
+        prop_assert_eq!(
+            proof.clone().verify(bad_root),
+            verify_from_iterators(bad_root)
+        );
+    }
+
+    #[proptest(cases = 30)]
+    fn verifying_presorted_proof_agrees_with_verifying_proof(
+        test_tree: MerkleTreeToTest,
+        corruptor: DigestCorruptor,
+    ) {
+        let leaf_indices = test_tree.selected_indices.iter().copied();
+        let leaf_indices = leaf_indices.sorted_unstable().dedup().collect_vec();
+        let proof = test_tree
+            .tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+
+        let root = test_tree.tree.root();
+        prop_assert_eq!(
+            proof.clone().verify(root),
+            proof.clone().verify_presorted(root)
+        );
+
+        let bad_root = corruptor.corrupt_digest(root)?;
+        prop_assert_eq!(
+            proof.clone().verify(bad_root),
+            proof.verify_presorted(bad_root)
+        );
+    }
+
+    #[test]
+    fn verifying_presorted_proof_with_unsorted_leaf_indices_fails() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(3);
+        let proof = tree.inclusion_proof_for_leaf_indices(&[5, 2]).unwrap();
+        assert!(proof.clone().verify(tree.root()));
+        assert!(!proof.verify_presorted(tree.root()));
+    }
+
+    #[test]
+    fn verifying_presorted_proof_with_duplicate_leaf_indices_fails() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(3);
+        let proof = tree.inclusion_proof_for_leaf_indices(&[2, 2]).unwrap();
+        assert!(proof.clone().verify(tree.root()));
+        assert!(!proof.verify_presorted(tree.root()));
+    }
+
+    fn indexed_authentication_structure(
+        tree: &MerkleTree<Tip5>,
+        leaf_indices: &[usize],
+    ) -> Vec<(usize, Digest)> {
+        let node_indices = MerkleTree::<Tip5>::authentication_structure_node_indices(
+            tree.num_leafs(),
+            leaf_indices,
+        );
+        let node_indices = node_indices.unwrap();
+        node_indices.map(|i| (i, tree.nodes[i])).collect()
+    }
+
+    #[proptest(cases = 30)]
+    fn honest_indexed_authentication_structure_can_be_verified_in_both_modes(
+        #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
+    ) {
+        let tree = &test_tree.tree;
+        let leaf_indices = &test_tree.selected_indices;
+        let mut indexed_nodes = indexed_authentication_structure(tree, leaf_indices);
+        indexed_nodes.reverse();
+
+        let indexed_leaves = tree.indexed_leaves(leaf_indices).unwrap();
+        let verify = |allow_extra_nodes| {
+            MerkleTreeInclusionProof::<Tip5>::verify_with_indexed_nodes(
+                tree.height(),
+                indexed_leaves.clone(),
+                &indexed_nodes,
+                tree.root(),
+                allow_extra_nodes,
+            )
+        };
+        prop_assert!(verify(false));
+        prop_assert!(verify(true));
+    }
+
+    #[proptest(cases = 50)]
+    fn authentication_structure_length_agrees_with_length_of_authentication_structure(
+        test_tree: MerkleTreeToTest,
+    ) {
+        let tree = &test_tree.tree;
+        let leaf_indices = &test_tree.selected_indices;
+        let authentication_structure = tree.authentication_structure(leaf_indices).unwrap();
+        let length =
+            MerkleTree::<Tip5>::authentication_structure_length(tree.height(), leaf_indices);
+        prop_assert_eq!(authentication_structure.len(), length.unwrap());
+    }
+
+    #[test]
+    fn authentication_structure_length_of_small_examples() {
+        let length = MerkleTree::<Tip5>::authentication_structure_length;
+        assert_eq!(Ok(0), length(0, &[0]));
+        assert_eq!(Ok(3), length(3, &[2]));
+        assert_eq!(Ok(3), length(3, &[0, 2]));
+        assert_eq!(Ok(0), length(3, &(0..8).collect_vec()));
+        assert_eq!(Ok(0), length(5, &[]));
+        assert_eq!(Ok(20), length(20, &[12345]));
+    }
+
+    #[test]
+    fn authentication_structure_length_rejects_invalid_input() {
+        let length = MerkleTree::<Tip5>::authentication_structure_length;
+        let err = length(3, &[8]).unwrap_err();
+        assert_eq!(MerkleTreeError::LeafIndexInvalid { num_leaves: 8 }, err);
+
+        let err = length(MAX_TREE_HEIGHT + 1, &[0]).unwrap_err();
+        assert_eq!(MerkleTreeError::TreeTooHigh, err);
+    }
+
+    #[test]
+    fn authentication_structure_padded_with_irrelevant_nodes_verifies_only_if_extras_are_allowed() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(3);
+        let leaf_indices = [0, 2];
+        let indexed_leaves = tree.indexed_leaves(&leaf_indices).unwrap();
+        let mut indexed_nodes = indexed_authentication_structure(&tree, &leaf_indices);
+        assert_eq!(
+            vec![11, 9, 3],
+            indexed_nodes.iter().map(|&(i, _)| i).collect_vec()
+        );
+
+        // node 5 can be computed; nodes 6, 7, and 13 are irrelevant for verification
+        for node_index in [5, 6, 7, 13] {
+            indexed_nodes.push((node_index, tree.nodes[node_index]));
+        }
+        let verify = |indexed_nodes: &[_], allow_extra_nodes| {
+            MerkleTreeInclusionProof::<Tip5>::verify_with_indexed_nodes(
+                tree.height(),
+                indexed_leaves.clone(),
+                indexed_nodes,
+                tree.root(),
+                allow_extra_nodes,
+            )
+        };
+        assert!(verify(&indexed_nodes, true));
+        assert!(!verify(&indexed_nodes, false));
+
+        let mut conflicting_nodes = indexed_nodes.clone();
+        conflicting_nodes.push((4, Digest::default()));
+        assert!(!verify(&conflicting_nodes, true));
+
+        let mut inconsistent_duplicates = indexed_nodes.clone();
+        inconsistent_duplicates.push((6, Digest::default()));
+        assert!(!verify(&inconsistent_duplicates, true));
+
+        let missing_needed_node = indexed_nodes.into_iter().filter(|&(i, _)| i != 9);
+        let missing_needed_node = missing_needed_node.collect_vec();
+        assert!(!verify(&missing_needed_node, true));
+    }
+
+    #[proptest(cases = 30)]
+    fn verifying_from_iterators_of_different_lengths_fails(
+        #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
+    ) {
+        let proof = test_tree.proof();
+        let num_leaves = proof.indexed_leaves.len();
+        let verdict = MerkleTreeInclusionProof::<Tip5>::verify_from_iterators(
+            proof.tree_height,
+            proof.indexed_leaves.iter().map(|&(index, _)| index),
+            proof
+                .indexed_leaves
+                .iter()
+                .map(|&(_, leaf)| leaf)
+                .take(num_leaves - 1),
+            proof.authentication_structure.iter().copied(),
+            test_tree.tree.root(),
+        );
+        prop_assert!(!verdict);
+    }
+
     #[proptest(cases = 30)]
     fn corrupt_root_leads_to_verification_failure(
         #[filter(#test_tree.has_non_trivial_proof())] test_tree: MerkleTreeToTest,
@@ -821,6 +2022,18 @@ pub mod merkle_tree_test {
         assert_eq!(MerkleTreeError::LeafIndexInvalid { num_leaves }, err);
     }
 
+    #[test]
+    fn all_authentication_paths_agree_with_single_leaf_authentication_structures() {
+        let tree = MerkleTree::<Tip5>::test_tree_of_height(8);
+        let all_paths = tree.all_authentication_paths().collect_vec();
+        assert_eq!(tree.num_leafs(), all_paths.len());
+        for (leaf_index, path) in all_paths.into_iter().enumerate() {
+            assert_eq!(tree.height(), path.len());
+            let auth_structure = tree.authentication_structure(&[leaf_index]).unwrap();
+            assert_eq!(auth_structure, path);
+        }
+    }
+
     #[test]
     fn authentication_paths_of_extremely_small_tree_use_expected_digests() {
         //     _ 1_
@@ -4,4 +4,18 @@ use crate::util_types::merkle_tree::*;
 
 pub trait MerkleTreeMaker<H: AlgebraicHasher> {
     fn from_digests(digests: &[Digest]) -> Result<MerkleTree<H>, MerkleTreeError>;
+
+    /// Like [`from_digests`](Self::from_digests), but every leaf is first hashed together with
+    /// the salt, _i.e._, the tree's leaves are `H::hash_pair(salt, digest)`. This prevents
+    /// brute-forcing low-entropy leaves from revealed digests without knowledge of the salt.
+    ///
+    /// Inclusion proofs must be verified using
+    /// [`verify_salted`](MerkleTreeInclusionProof::verify_salted).
+    fn from_digests_salted(
+        digests: &[Digest],
+        salt: Digest,
+    ) -> Result<MerkleTree<H>, MerkleTreeError> {
+        let salted_digests: Vec<_> = digests.iter().map(|&d| H::hash_pair(salt, d)).collect();
+        Self::from_digests(&salted_digests)
+    }
 }
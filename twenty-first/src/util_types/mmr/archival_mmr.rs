@@ -4,6 +4,7 @@ use leveldb::batch::WriteBatch;
 
 use crate::shared_math::digest::Digest;
 use crate::storage::storage_vec::traits::*;
+use crate::storage::storage_vec::OrdinaryVec;
 use crate::storage::storage_vec::RustyLevelDbVec;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
 use crate::util_types::shared::bag_peaks;
@@ -313,6 +314,18 @@ impl<H: AlgebraicHasher, Storage: StorageVec<Digest>> ArchivalMmr<H, Storage> {
     }
 }
 
+/// An append-only Merkle Mountain Range living entirely in memory.
+///
+/// Appending a leaf is amortized `O(log n)`. Use [`prove_membership`](ArchivalMmr::prove_membership)
+/// to get a membership proof and [`MmrMembershipProof::verify`] to verify it against the peaks.
+pub type MerkleMountainRange<H> = ArchivalMmr<H, OrdinaryVec<Digest>>;
+
+impl<H: AlgebraicHasher> Default for ArchivalMmr<H, OrdinaryVec<Digest>> {
+    fn default() -> Self {
+        Self::new(OrdinaryVec::from(vec![]))
+    }
+}
+
 impl<H: AlgebraicHasher> ArchivalMmr<H, RustyLevelDbVec<Digest>> {
     /// Add write queue to referenced write batch. Leaves cache and write queue empty.
     pub fn persist(&mut self, write_batch: &mut WriteBatch) {
@@ -731,6 +744,26 @@ mod mmr_test {
         }
     }
 
+    #[test]
+    fn every_leaf_appended_to_in_memory_mmr_can_be_proven_against_bagged_peaks() {
+        type H = Tip5;
+
+        let leaves: Vec<Digest> = random_elements(1000);
+        let mut mmr = MerkleMountainRange::<H>::default();
+        for &leaf in &leaves {
+            mmr.append(leaf);
+        }
+        assert_eq!(leaves.len() as u64, mmr.count_leaves());
+
+        let bagged_peaks = mmr.bag_peaks();
+        for (leaf_index, &leaf) in leaves.iter().enumerate() {
+            let (membership_proof, peaks) = mmr.prove_membership(leaf_index as u64);
+            assert_eq!(bagged_peaks, bag_peaks::<H>(&peaks));
+            let (is_valid, _) = membership_proof.verify(&peaks, leaf, mmr.count_leaves());
+            assert!(is_valid);
+        }
+    }
+
     #[test]
     fn one_input_mmr_test() {
         type H = Tip5;
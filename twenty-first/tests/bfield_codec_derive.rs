@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use arbitrary::Arbitrary;
 use proptest::prelude::*;
 use proptest_arbitrary_interop::arb;
@@ -65,10 +67,124 @@ fn integration_test_enum_b(#[strategy(arb())] test_enum: BFieldCodecTestEnumB) {
     prop_assert_eq!(test_enum, decoding);
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, BFieldCodec, Arbitrary)]
+struct BFieldCodecTestStructWithConditionalFieldPresent {
+    a: u64,
+    b: Vec<XFieldElement>,
+    #[cfg(all())]
+    #[bfield_codec(ignore)]
+    c: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BFieldCodec, Arbitrary)]
+struct BFieldCodecTestStructWithConditionalFieldAbsent {
+    a: u64,
+    b: Vec<XFieldElement>,
+    #[cfg(any())]
+    #[bfield_codec(ignore)]
+    c: u32,
+}
+
+#[proptest]
+fn conditionally_compiled_fields_do_not_influence_encoding(
+    #[strategy(arb())] present: BFieldCodecTestStructWithConditionalFieldPresent,
+) {
+    let absent = BFieldCodecTestStructWithConditionalFieldAbsent {
+        a: present.a,
+        b: present.b.clone(),
+    };
+    prop_assert_eq!(present.encode(), absent.encode());
+
+    let decoding =
+        *BFieldCodecTestStructWithConditionalFieldAbsent::decode(&present.encode()).unwrap();
+    prop_assert_eq!(absent, decoding);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BFieldCodec, Arbitrary)]
+struct BFieldCodecTestStructWithVecOfBFieldElements {
+    a: u32,
+    b: Vec<BFieldElement>,
+}
+
+#[proptest]
+fn vec_field_has_length_prepended_encoding(
+    #[strategy(arb())] test_struct: BFieldCodecTestStructWithVecOfBFieldElements,
+) {
+    // fields are encoded in reverse order
+    let num_elements = test_struct.b.len() as u64;
+    let mut expected_encoding = vec![
+        BFieldElement::new(num_elements + 1),
+        BFieldElement::new(num_elements),
+    ];
+    expected_encoding.extend(test_struct.b.clone());
+    expected_encoding.push(BFieldElement::new(test_struct.a.into()));
+
+    let encoding = test_struct.encode();
+    prop_assert_eq!(expected_encoding, encoding.clone());
+
+    let decoding = *BFieldCodecTestStructWithVecOfBFieldElements::decode(&encoding).unwrap();
+    prop_assert_eq!(test_struct, decoding);
+}
+
+#[proptest]
+fn vec_field_with_inconsistent_number_of_elements_fails_to_decode(
+    #[strategy(arb())] test_struct: BFieldCodecTestStructWithVecOfBFieldElements,
+) {
+    let mut encoding = test_struct.encode();
+    let num_elements_index = 1;
+    encoding[num_elements_index].increment();
+    prop_assert!(BFieldCodecTestStructWithVecOfBFieldElements::decode(&encoding).is_err());
+}
+
+#[proptest]
+fn decoding_vec_field_agrees_with_decoding_vec(
+    #[strategy(arb())] sequence: Vec<BFieldElement>,
+    #[strategy(arb())] elements: Vec<XFieldElement>,
+) {
+    use twenty_first::shared_math::bfield_codec::decode_vec_field;
+
+    let random_decoding = decode_vec_field::<XFieldElement>(&sequence).ok();
+    let random_vec_decoding = Vec::<XFieldElement>::decode(&sequence).ok().map(|vec| *vec);
+    prop_assert_eq!(random_vec_decoding, random_decoding);
+
+    let encoding = elements.encode();
+    let decoding = decode_vec_field::<XFieldElement>(&encoding).unwrap();
+    prop_assert_eq!(elements, decoding);
+}
+
+/// Deliberately does not implement `BFieldCodec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NotEncodable;
+
+#[derive(Debug, Clone, PartialEq, Eq, BFieldCodec)]
+struct Tagged<T> {
+    value: u64,
+    _marker: PhantomData<T>,
+}
+
+#[proptest]
+fn struct_with_phantom_data_of_non_encodable_type_round_trips(value: u64) {
+    let tagged = Tagged::<NotEncodable> {
+        value,
+        _marker: PhantomData,
+    };
+    let encoding = tagged.encode();
+    prop_assert_eq!(value.encode(), encoding.clone());
+    prop_assert_eq!(
+        u64::static_length(),
+        Tagged::<NotEncodable>::static_length()
+    );
+
+    let decoding = *Tagged::<NotEncodable>::decode(&encoding).unwrap();
+    prop_assert_eq!(tagged, decoding);
+}
+
 #[test]
 fn try_build_various_failure_cases() {
     let trybuild = trybuild::TestCases::new();
     trybuild.compile_fail("trybuild/multiple_field_attributes.rs");
     trybuild.compile_fail("trybuild/incorrect_field_attribute.rs");
+    trybuild.compile_fail("trybuild/conditionally_compiled_field.rs");
+    trybuild.compile_fail("trybuild/enum_without_variants.rs");
     trybuild.pass("trybuild/missing_field_attribute.rs");
 }
@@ -0,0 +1,11 @@
+use twenty_first;
+use twenty_first::shared_math::bfield_codec::BFieldCodec;
+
+#[derive(BFieldCodec)]
+struct MyStruct {
+    a: u32,
+    #[cfg(all())]
+    b: u32,
+}
+
+fn main() {}
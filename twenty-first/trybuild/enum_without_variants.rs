@@ -0,0 +1,7 @@
+use twenty_first;
+use twenty_first::shared_math::bfield_codec::BFieldCodec;
+
+#[derive(BFieldCodec)]
+enum Never {}
+
+fn main() {}